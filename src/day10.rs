@@ -63,7 +63,7 @@ impl Asteroid {
 fn parse(from: &str) -> Vec<Asteroid> {
     from.lines()
         .enumerate()
-        .map(|(y, line)| {
+        .flat_map(|(y, line)| {
             line.chars()
                 .enumerate()
                 .flat_map(|(x, c)| {
@@ -78,7 +78,6 @@ fn parse(from: &str) -> Vec<Asteroid> {
                 })
                 .collect::<Vec<Asteroid>>()
         })
-        .flatten()
         .collect()
 }
 
@@ -92,7 +91,7 @@ fn shooting_order(from: &Asteroid, universe: &[Asteroid]) -> Vec<Asteroid> {
     while let Some(asteroid) = pool.pop() {
         let mut current = VecDeque::new();
         current.push_front(asteroid.clone());
-        current.extend(pool.drain_filter(|other| from.collinear(&asteroid, &other)));
+        current.extend(pool.extract_if(.., |other| from.collinear(&asteroid, other)));
         colinears.push(
             current
                 .iter()
@@ -130,7 +129,7 @@ pub fn main() {
         .map(|asteroid| (asteroid, asteroid.calculate_visible(&asteroids)))
         .max_by(|(_, a), (_, b)| a.cmp(b))
         .unwrap();
-    let order = shooting_order(&winner, &asteroids);
+    let order = shooting_order(winner, &asteroids);
 
     let mut space = HashMap::with_capacity(asteroids.len());
     for asteroid in &asteroids {
@@ -69,7 +69,7 @@ impl Moon {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
 struct Velocity {
     x: i32,
     y: i32,
@@ -82,12 +82,6 @@ impl Velocity {
     }
 }
 
-impl Default for Velocity {
-    fn default() -> Velocity {
-        Velocity { x: 0, y: 0, z: 0 }
-    }
-}
-
 impl ops::Add<Velocity> for Velocity {
     type Output = Velocity;
 
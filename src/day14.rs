@@ -34,7 +34,6 @@ impl System {
 enum Material {
     Ore,
     Chemical {
-        name: String,
         inputs: Vec<(String, usize)>,
         output: usize,
     },
@@ -47,11 +46,7 @@ impl Material {
                 system.cost += 1;
                 1
             }
-            Material::Chemical {
-                name,
-                inputs,
-                output,
-            } => {
+            Material::Chemical { inputs, output } => {
                 for (name, amount) in inputs {
                     system.take(*amount, name.clone())
                 }
@@ -73,14 +68,7 @@ fn parse(line: &str) -> (String, Material) {
         .map(|p| p.split(' ').collect::<Vec<&str>>())
         .map(|v| (v[1].to_string(), v[0].parse::<usize>().unwrap()))
         .collect();
-    (
-        name.clone(),
-        Material::Chemical {
-            name,
-            inputs,
-            output,
-        },
-    )
+    (name, Material::Chemical { inputs, output })
 }
 
 pub fn main() {
@@ -89,7 +77,7 @@ pub fn main() {
     let mut system = System::new(chemicals);
     system
         .chemicals
-        .get(&"FUEL".to_string())
+        .get("FUEL")
         .unwrap()
         .clone()
         .produce(&mut system);
@@ -1,4 +1,4 @@
-use itertools::{chain, Itertools};
+use itertools::Itertools;
 
 fn parse(data: &str) -> Vec<i16> {
     data.chars()
@@ -8,12 +8,12 @@ fn parse(data: &str) -> Vec<i16> {
 }
 
 fn make_pattern(counter: usize) -> impl Iterator<Item = i16> {
-    let multiplier = (counter + 1);
+    let multiplier = counter + 1;
     vec![0; multiplier]
         .into_iter()
-        .chain(vec![1; multiplier].into_iter())
-        .chain(vec![0; multiplier].into_iter())
-        .chain(vec![-1; multiplier].into_iter())
+        .chain(vec![1; multiplier])
+        .chain(vec![0; multiplier])
+        .chain(vec![-1; multiplier])
         .cycle()
         .skip(1)
 }
@@ -24,7 +24,7 @@ fn make_patterns(num: usize) -> Vec<Vec<i16>> {
         .collect()
 }
 
-fn phase(input: Vec<i16>, patterns: &Vec<Vec<i16>>) -> Vec<i16> {
+fn phase(input: Vec<i16>, patterns: &[Vec<i16>]) -> Vec<i16> {
     patterns
         .iter()
         .map(|pattern| {
@@ -1,5 +1,4 @@
 use crate::render::render;
-use itertools::Itertools;
 use pathfinding::prelude::*;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -1,5 +1,3 @@
-use std::ops::Mul;
-
 type Deck = Vec<u16>;
 type Technique = Vec<Shuffle>;
 
@@ -16,7 +14,7 @@ impl Shuffle {
                 let index = if n > &0 {
                     *n as usize
                 } else {
-                    deck.len() - (n.abs() as usize)
+                    deck.len() - (n.unsigned_abs() as usize)
                 };
                 let length = deck.len();
                 deck.into_iter().cycle().skip(index).take(length).collect()
@@ -37,10 +35,10 @@ impl Shuffle {
 
 impl From<&str> for Shuffle {
     fn from(value: &str) -> Self {
-        if value.starts_with("cut ") {
-            Shuffle::Cut(value[4..].parse::<i16>().unwrap())
-        } else if value.starts_with("deal with") {
-            Shuffle::DealIncrement(value[20..].parse::<usize>().unwrap())
+        if let Some(count) = value.strip_prefix("cut ") {
+            Shuffle::Cut(count.parse::<i16>().unwrap())
+        } else if let Some(increment) = value.strip_prefix("deal with increment ") {
+            Shuffle::DealIncrement(increment.parse::<usize>().unwrap())
         } else {
             Shuffle::NewStack
         }
@@ -1,8 +1,8 @@
-use crate::render::render;
-use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
-const NEIGHBORS: [fn(i64, i64) -> (i64, i64); 4] = [
+type Neighbor = fn(i64, i64) -> (i64, i64);
+
+const NEIGHBORS: [Neighbor; 4] = [
     |x, y| (x - 1, y),
     |x, y| (x + 1, y),
     |x, y| (x, y - 1),
@@ -68,12 +68,6 @@ impl Grid {
             .map(|index| 2u64.pow(index))
             .sum()
     }
-
-    fn renderable(&self) -> impl Iterator<Item = ((i64, i64), char)> + '_ {
-        self.bugs
-            .iter()
-            .map(move |(&key, &bug)| (key, if bug { '#' } else { '.' }))
-    }
 }
 
 pub fn main() {
@@ -86,9 +86,8 @@ pub fn main() {
         .iter()
         .flat_map(|&coords| {
             first
-                .get(&coords)
-                .map(|a| second.get(&coords).map(|b| a + b))
-                .flatten()
+                .get(coords)
+                .and_then(|a| second.get(coords).map(|b| a + b))
         })
         .min()
         .unwrap();
@@ -13,7 +13,7 @@ impl Object {
             satellites: match universe.get(&name) {
                 Some(names) => names
                     .iter()
-                    .map(|name| Object::new(name.to_string(), &universe))
+                    .map(|name| Object::new(name.to_string(), universe))
                     .collect(),
                 None => Vec::new(),
             },
@@ -1,79 +1,29 @@
-use std::collections::VecDeque;
-use std::sync::mpsc::channel;
+use std::ops::RangeInclusive;
 
 use itertools::Itertools;
-use threadpool::ThreadPool;
 
-use crate::vm::{run, InputOutput, Memory, SendOrStore};
+use crate::vm::{Pipeline, Program, Topology};
 
-pub fn main() {
-    let memory = Memory::from(INPUT);
-    println!(
-        "{}",
-        (0..=4i64)
-            .permutations(5)
-            .map(|amps| {
-                let mut output = 0i64;
-                for &amp in amps.iter() {
-                    output = run(
-                        memory.clone(),
-                        InputOutput::new(VecDeque::from(vec![amp, output]), 0i64),
-                    )
-                    .1;
-                }
-                output
-            })
-            .max()
-            .unwrap()
-    );
+fn max_signal(program: &Program, phases: RangeInclusive<i64>, topology: Topology) -> i64 {
+    phases
+        .permutations(5)
+        .map(|phases| {
+            Pipeline::new(program, 5)
+                .topology(topology)
+                .phases(&phases)
+                .inject(0)
+                .run()
+                .unwrap()
+                .unwrap()
+        })
+        .max()
+        .unwrap()
+}
 
-    let pool = ThreadPool::new(5);
-    let mut winner = 0;
-    for amps in (5..=9i64).permutations(5) {
-        let (signalout, signalin) = channel();
-        let (aout, bin) = channel();
-        let (bout, cin) = channel();
-        let (cout, din) = channel();
-        let (dout, ein) = channel();
-        let (eout, ain) = channel();
-        aout.send(amps[1]).unwrap();
-        bout.send(amps[2]).unwrap();
-        cout.send(amps[3]).unwrap();
-        dout.send(amps[4]).unwrap();
-        eout.send(amps[0]).unwrap();
-        eout.send(0).unwrap();
-        let eout = SendOrStore::new(eout);
-        let amem = memory.clone();
-        let bmem = memory.clone();
-        let cmem = memory.clone();
-        let dmem = memory.clone();
-        let emem = memory.clone();
-        pool.execute(move || {
-            run(amem, InputOutput::new(ain, aout));
-        });
-        pool.execute(move || {
-            run(bmem, InputOutput::new(bin, bout));
-        });
-        pool.execute(move || {
-            run(cmem, InputOutput::new(cin, cout));
-        });
-        pool.execute(move || {
-            run(dmem, InputOutput::new(din, dout));
-        });
-        pool.execute(move || {
-            let (_, out) = run(emem, InputOutput::new(ein, eout));
-            signalout.send(*out.last().unwrap()).unwrap();
-        });
-        pool.join();
-        let candidate = signalin.recv().unwrap();
-        if candidate > winner {
-            winner = candidate;
-        }
-        if pool.panic_count() != 0 {
-            panic!("something panicked!");
-        }
-    }
-    println!("{}", winner);
+pub fn main() {
+    let program = Program::from(INPUT);
+    println!("{}", max_signal(&program, 0..=4, Topology::Linear));
+    println!("{}", max_signal(&program, 5..=9, Topology::Ring));
 }
 
-const INPUT: &str = "3,8,1001,8,10,8,105,1,0,0,21,42,67,88,101,114,195,276,357,438,99999,3,9,101,3,9,9,1002,9,4,9,1001,9,5,9,102,4,9,9,4,9,99,3,9,1001,9,3,9,1002,9,2,9,101,2,9,9,102,2,9,9,1001,9,5,9,4,9,99,3,9,102,4,9,9,1001,9,3,9,102,4,9,9,101,4,9,9,4,9,99,3,9,101,2,9,9,1002,9,3,9,4,9,99,3,9,101,4,9,9,1002,9,5,9,4,9,99,3,9,102,2,9,9,4,9,3,9,1001,9,1,9,4,9,3,9,101,1,9,9,4,9,3,9,1001,9,1,9,4,9,3,9,101,1,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,101,1,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,102,2,9,9,4,9,3,9,1002,9,2,9,4,9,99,3,9,102,2,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,1001,9,1,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1001,9,2,9,4,9,3,9,1001,9,2,9,4,9,3,9,1001,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,101,1,9,9,4,9,99,3,9,102,2,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,1001,9,2,9,4,9,3,9,102,2,9,9,4,9,3,9,1001,9,2,9,4,9,3,9,101,2,9,9,4,9,3,9,1001,9,1,9,4,9,3,9,101,1,9,9,4,9,3,9,101,2,9,9,4,9,3,9,1001,9,1,9,4,9,99,3,9,102,2,9,9,4,9,3,9,101,1,9,9,4,9,3,9,1001,9,1,9,4,9,3,9,101,1,9,9,4,9,3,9,101,1,9,9,4,9,3,9,101,1,9,9,4,9,3,9,1001,9,2,9,4,9,3,9,101,2,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,1001,9,1,9,4,9,99,3,9,1001,9,2,9,4,9,3,9,102,2,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,101,2,9,9,4,9,3,9,101,2,9,9,4,9,99";
+pub const INPUT: &str = "3,8,1001,8,10,8,105,1,0,0,21,42,67,88,101,114,195,276,357,438,99999,3,9,101,3,9,9,1002,9,4,9,1001,9,5,9,102,4,9,9,4,9,99,3,9,1001,9,3,9,1002,9,2,9,101,2,9,9,102,2,9,9,1001,9,5,9,4,9,99,3,9,102,4,9,9,1001,9,3,9,102,4,9,9,101,4,9,9,4,9,99,3,9,101,2,9,9,1002,9,3,9,4,9,99,3,9,101,4,9,9,1002,9,5,9,4,9,99,3,9,102,2,9,9,4,9,3,9,1001,9,1,9,4,9,3,9,101,1,9,9,4,9,3,9,1001,9,1,9,4,9,3,9,101,1,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,101,1,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,102,2,9,9,4,9,3,9,1002,9,2,9,4,9,99,3,9,102,2,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,1001,9,1,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1001,9,2,9,4,9,3,9,1001,9,2,9,4,9,3,9,1001,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,101,1,9,9,4,9,99,3,9,102,2,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,1001,9,2,9,4,9,3,9,102,2,9,9,4,9,3,9,1001,9,2,9,4,9,3,9,101,2,9,9,4,9,3,9,1001,9,1,9,4,9,3,9,101,1,9,9,4,9,3,9,101,2,9,9,4,9,3,9,1001,9,1,9,4,9,99,3,9,102,2,9,9,4,9,3,9,101,1,9,9,4,9,3,9,1001,9,1,9,4,9,3,9,101,1,9,9,4,9,3,9,101,1,9,9,4,9,3,9,101,1,9,9,4,9,3,9,1001,9,2,9,4,9,3,9,101,2,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,1001,9,1,9,4,9,99,3,9,1001,9,2,9,4,9,3,9,102,2,9,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,1002,9,2,9,4,9,3,9,101,2,9,9,4,9,3,9,101,2,9,9,4,9,99";
@@ -1,7 +1,3 @@
-#![feature(drain_filter)]
-#![feature(tau_constant)]
-#![feature(deadline_api)]
-
 #[macro_use]
 extern crate itertools;
 extern crate pathfinding;
@@ -33,8 +29,9 @@ mod day6;
 mod day7;
 mod day8;
 mod day9;
+mod native;
 pub mod render;
-pub mod twod;
+mod tools;
 pub mod vm;
 
 const DAYS: [fn(); 25] = [
@@ -66,12 +63,17 @@ const DAYS: [fn(); 25] = [
 ];
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(tool) = args.first().and_then(|name| tools::get(name)) {
+        if let Err(error) = tool(&args[1..]) {
+            eprintln!("{}", error);
+        }
+        return;
+    }
     let day = std::env::args()
         .nth(1)
-        .map(|arg| arg.parse::<usize>().ok())
-        .flatten()
-        .map(|index| index.checked_sub(1))
-        .flatten()
+        .and_then(|arg| arg.parse::<usize>().ok())
+        .and_then(|index| index.checked_sub(1))
         .map(|index| DAYS.get(index))
         .unwrap_or_else(|| DAYS.last())
         .unwrap();
@@ -0,0 +1,2351 @@
+// generated by the transpile tool, do not edit
+use crate::vm::{checked_address, checked_relative, interpret_from, Memory, VmError, IO};
+
+#[allow(unused_mut, unused_assignments, clippy::all)]
+pub fn run<I: IO>(mut memory: Memory, mut io: I) -> Result<(Memory, I::Value), VmError> {
+    let mut pc: usize = 0;
+    let mut rb: i64 = 0;
+    'run: {
+        loop {
+            match pc {
+                0 if memory[0] == 1102 => {
+                    let a = memory[1];
+                    let b = memory[2];
+                    let Some(to) = checked_address(&memory, memory[3]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 4;
+                }
+                4 if memory[4] == 1007 => {
+                    let Some(a) = checked_address(&memory, memory[5]).map(|at| memory[at]) else { break };
+                    let b = memory[6];
+                    let Some(to) = checked_address(&memory, memory[7]) else { break };
+                    memory[to] = (a < b) as i64;
+                    pc = 8;
+                }
+                8 if memory[8] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[9]).map(|at| memory[at]) else { break };
+                    let b = memory[10];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 11;
+                }
+                11 if memory[11] == 1102 => {
+                    let a = memory[12];
+                    let b = memory[13];
+                    let Some(to) = checked_address(&memory, memory[14]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 15;
+                }
+                15 if memory[15] == 109 => {
+                    let a = memory[16];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 17;
+                }
+                17 if memory[17] == 209 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[18]).map(|at| memory[at]) else { break };
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 19;
+                }
+                19 if memory[19] == 9 => {
+                    let Some(a) = checked_address(&memory, memory[20]).map(|at| memory[at]) else { break };
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 21;
+                }
+                21 if memory[21] == 209 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[22]).map(|at| memory[at]) else { break };
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 23;
+                }
+                23 if memory[23] == 209 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[24]).map(|at| memory[at]) else { break };
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 25;
+                }
+                25 if memory[25] == 203 => {
+                    let Some(to) = checked_relative(&memory, rb, memory[26]) else { break };
+                    let Some(value) = io.read() else { break 'run };
+                    memory[to] = value;
+                    pc = 27;
+                }
+                27 if memory[27] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[28]).map(|at| memory[at]) else { break };
+                    let b = memory[29];
+                    let Some(to) = checked_address(&memory, memory[30]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 31;
+                }
+                31 if memory[31] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[32]).map(|at| memory[at]) else { break };
+                    let b = memory[33];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 34;
+                }
+                34 if memory[34] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[35]).map(|at| memory[at]) else { break };
+                    let b = memory[36];
+                    let Some(to) = checked_address(&memory, memory[37]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 38;
+                }
+                38 if memory[38] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[39]).map(|at| memory[at]) else { break };
+                    let b = memory[40];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 41;
+                }
+                41 if memory[41] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[42]).map(|at| memory[at]) else { break };
+                    let b = memory[43];
+                    let Some(to) = checked_address(&memory, memory[44]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 45;
+                }
+                45 if memory[45] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[46]).map(|at| memory[at]) else { break };
+                    let b = memory[47];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 48;
+                }
+                48 if memory[48] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[49]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 50;
+                }
+                50 if memory[50] == 104 => {
+                    let a = memory[51];
+                    if io.write(a).is_none() { break 'run }
+                    pc = 52;
+                }
+                52 if memory[52] == 99 => {
+                    break 'run;
+                }
+                53 if memory[53] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[54]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 55;
+                }
+                55 if memory[55] == 104 => {
+                    let a = memory[56];
+                    if io.write(a).is_none() { break 'run }
+                    pc = 57;
+                }
+                57 if memory[57] == 99 => {
+                    break 'run;
+                }
+                58 if memory[58] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[59]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 60;
+                }
+                60 if memory[60] == 104 => {
+                    let a = memory[61];
+                    if io.write(a).is_none() { break 'run }
+                    pc = 62;
+                }
+                62 if memory[62] == 99 => {
+                    break 'run;
+                }
+                65 if memory[65] == 1102 => {
+                    let a = memory[66];
+                    let b = memory[67];
+                    let Some(to) = checked_address(&memory, memory[68]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 69;
+                }
+                69 if memory[69] == 1101 => {
+                    let a = memory[70];
+                    let b = memory[71];
+                    let Some(to) = checked_address(&memory, memory[72]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 73;
+                }
+                73 if memory[73] == 1102 => {
+                    let a = memory[74];
+                    let b = memory[75];
+                    let Some(to) = checked_address(&memory, memory[76]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 77;
+                }
+                77 if memory[77] == 1101 => {
+                    let a = memory[78];
+                    let b = memory[79];
+                    let Some(to) = checked_address(&memory, memory[80]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 81;
+                }
+                81 if memory[81] == 1101 => {
+                    let a = memory[82];
+                    let b = memory[83];
+                    let Some(to) = checked_address(&memory, memory[84]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 85;
+                }
+                85 if memory[85] == 1102 => {
+                    let a = memory[86];
+                    let b = memory[87];
+                    let Some(to) = checked_address(&memory, memory[88]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 89;
+                }
+                89 if memory[89] == 1101 => {
+                    let a = memory[90];
+                    let b = memory[91];
+                    let Some(to) = checked_address(&memory, memory[92]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 93;
+                }
+                93 if memory[93] == 1101 => {
+                    let a = memory[94];
+                    let b = memory[95];
+                    let Some(to) = checked_address(&memory, memory[96]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 97;
+                }
+                97 if memory[97] == 1102 => {
+                    let a = memory[98];
+                    let b = memory[99];
+                    let Some(to) = checked_address(&memory, memory[100]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 101;
+                }
+                101 if memory[101] == 1101 => {
+                    let a = memory[102];
+                    let b = memory[103];
+                    let Some(to) = checked_address(&memory, memory[104]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 105;
+                }
+                105 if memory[105] == 1101 => {
+                    let a = memory[106];
+                    let b = memory[107];
+                    let Some(to) = checked_address(&memory, memory[108]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 109;
+                }
+                109 if memory[109] == 1102 => {
+                    let a = memory[110];
+                    let b = memory[111];
+                    let Some(to) = checked_address(&memory, memory[112]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 113;
+                }
+                113 if memory[113] == 1101 => {
+                    let a = memory[114];
+                    let b = memory[115];
+                    let Some(to) = checked_address(&memory, memory[116]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 117;
+                }
+                117 if memory[117] == 1102 => {
+                    let a = memory[118];
+                    let b = memory[119];
+                    let Some(to) = checked_address(&memory, memory[120]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 121;
+                }
+                121 if memory[121] == 1101 => {
+                    let a = memory[122];
+                    let b = memory[123];
+                    let Some(to) = checked_address(&memory, memory[124]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 125;
+                }
+                125 if memory[125] == 1101 => {
+                    let a = memory[126];
+                    let b = memory[127];
+                    let Some(to) = checked_address(&memory, memory[128]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 129;
+                }
+                129 if memory[129] == 1102 => {
+                    let a = memory[130];
+                    let b = memory[131];
+                    let Some(to) = checked_address(&memory, memory[132]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 133;
+                }
+                133 if memory[133] == 1102 => {
+                    let a = memory[134];
+                    let b = memory[135];
+                    let Some(to) = checked_address(&memory, memory[136]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 137;
+                }
+                137 if memory[137] == 1102 => {
+                    let a = memory[138];
+                    let b = memory[139];
+                    let Some(to) = checked_address(&memory, memory[140]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 141;
+                }
+                141 if memory[141] == 1101 => {
+                    let a = memory[142];
+                    let b = memory[143];
+                    let Some(to) = checked_address(&memory, memory[144]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 145;
+                }
+                145 if memory[145] == 1102 => {
+                    let a = memory[146];
+                    let b = memory[147];
+                    let Some(to) = checked_address(&memory, memory[148]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 149;
+                }
+                149 if memory[149] == 1102 => {
+                    let a = memory[150];
+                    let b = memory[151];
+                    let Some(to) = checked_address(&memory, memory[152]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 153;
+                }
+                153 if memory[153] == 1102 => {
+                    let a = memory[154];
+                    let b = memory[155];
+                    let Some(to) = checked_address(&memory, memory[156]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 157;
+                }
+                157 if memory[157] == 1102 => {
+                    let a = memory[158];
+                    let b = memory[159];
+                    let Some(to) = checked_address(&memory, memory[160]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 161;
+                }
+                161 if memory[161] == 1102 => {
+                    let a = memory[162];
+                    let b = memory[163];
+                    let Some(to) = checked_address(&memory, memory[164]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 165;
+                }
+                165 if memory[165] == 1102 => {
+                    let a = memory[166];
+                    let b = memory[167];
+                    let Some(to) = checked_address(&memory, memory[168]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 169;
+                }
+                169 if memory[169] == 1102 => {
+                    let a = memory[170];
+                    let b = memory[171];
+                    let Some(to) = checked_address(&memory, memory[172]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 173;
+                }
+                173 if memory[173] == 1101 => {
+                    let a = memory[174];
+                    let b = memory[175];
+                    let Some(to) = checked_address(&memory, memory[176]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 177;
+                }
+                177 if memory[177] == 1102 => {
+                    let a = memory[178];
+                    let b = memory[179];
+                    let Some(to) = checked_address(&memory, memory[180]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 181;
+                }
+                181 if memory[181] == 1101 => {
+                    let a = memory[182];
+                    let b = memory[183];
+                    let Some(to) = checked_address(&memory, memory[184]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 185;
+                }
+                185 if memory[185] == 109 => {
+                    let a = memory[186];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 187;
+                }
+                187 if memory[187] == 1205 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[188]).map(|at| memory[at]) else { break };
+                    let b = memory[189];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 190;
+                }
+                190 if memory[190] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[191]).map(|at| memory[at]) else { break };
+                    let b = memory[192];
+                    let Some(to) = checked_address(&memory, memory[193]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 194;
+                }
+                194 if memory[194] == 1106 => {
+                    let a = memory[195];
+                    let b = memory[196];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 197;
+                }
+                197 if memory[197] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[198]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 199;
+                }
+                199 if memory[199] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[200]).map(|at| memory[at]) else { break };
+                    let b = memory[201];
+                    let Some(to) = checked_address(&memory, memory[202]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 203;
+                }
+                203 if memory[203] == 109 => {
+                    let a = memory[204];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 205;
+                }
+                205 if memory[205] == 1207 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[206]).map(|at| memory[at]) else { break };
+                    let b = memory[207];
+                    let Some(to) = checked_address(&memory, memory[208]) else { break };
+                    memory[to] = (a < b) as i64;
+                    pc = 209;
+                }
+                209 if memory[209] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[210]).map(|at| memory[at]) else { break };
+                    let b = memory[211];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 212;
+                }
+                212 if memory[212] == 1105 => {
+                    let a = memory[213];
+                    let b = memory[214];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 215;
+                }
+                215 if memory[215] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[216]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 217;
+                }
+                217 if memory[217] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[218]).map(|at| memory[at]) else { break };
+                    let b = memory[219];
+                    let Some(to) = checked_address(&memory, memory[220]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 221;
+                }
+                221 if memory[221] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[222]).map(|at| memory[at]) else { break };
+                    let b = memory[223];
+                    let Some(to) = checked_address(&memory, memory[224]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 225;
+                }
+                225 if memory[225] == 109 => {
+                    let a = memory[226];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 227;
+                }
+                227 if memory[227] == 1208 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[228]).map(|at| memory[at]) else { break };
+                    let b = memory[229];
+                    let Some(to) = checked_address(&memory, memory[230]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 231;
+                }
+                231 if memory[231] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[232]).map(|at| memory[at]) else { break };
+                    let b = memory[233];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 234;
+                }
+                234 if memory[234] == 1106 => {
+                    let a = memory[235];
+                    let b = memory[236];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 237;
+                }
+                237 if memory[237] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[238]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 239;
+                }
+                239 if memory[239] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[240]).map(|at| memory[at]) else { break };
+                    let b = memory[241];
+                    let Some(to) = checked_address(&memory, memory[242]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 243;
+                }
+                243 if memory[243] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[244]).map(|at| memory[at]) else { break };
+                    let b = memory[245];
+                    let Some(to) = checked_address(&memory, memory[246]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 247;
+                }
+                247 if memory[247] == 109 => {
+                    let a = memory[248];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 249;
+                }
+                249 if memory[249] == 2102 => {
+                    let a = memory[250];
+                    let Some(b) = checked_relative(&memory, rb, memory[251]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_address(&memory, memory[252]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 253;
+                }
+                253 if memory[253] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[254]).map(|at| memory[at]) else { break };
+                    let b = memory[255];
+                    let Some(to) = checked_address(&memory, memory[256]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 257;
+                }
+                257 if memory[257] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[258]).map(|at| memory[at]) else { break };
+                    let b = memory[259];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 260;
+                }
+                260 if memory[260] == 1105 => {
+                    let a = memory[261];
+                    let b = memory[262];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 263;
+                }
+                263 if memory[263] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[264]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 265;
+                }
+                265 if memory[265] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[266]).map(|at| memory[at]) else { break };
+                    let b = memory[267];
+                    let Some(to) = checked_address(&memory, memory[268]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 269;
+                }
+                269 if memory[269] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[270]).map(|at| memory[at]) else { break };
+                    let b = memory[271];
+                    let Some(to) = checked_address(&memory, memory[272]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 273;
+                }
+                273 if memory[273] == 109 => {
+                    let a = memory[274];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 275;
+                }
+                275 if memory[275] == 21107 => {
+                    let a = memory[276];
+                    let b = memory[277];
+                    let Some(to) = checked_relative(&memory, rb, memory[278]) else { break };
+                    memory[to] = (a < b) as i64;
+                    pc = 279;
+                }
+                279 if memory[279] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[280]).map(|at| memory[at]) else { break };
+                    let b = memory[281];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 282;
+                }
+                282 if memory[282] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[283]).map(|at| memory[at]) else { break };
+                    let b = memory[284];
+                    let Some(to) = checked_address(&memory, memory[285]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 286;
+                }
+                286 if memory[286] == 1106 => {
+                    let a = memory[287];
+                    let b = memory[288];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 289;
+                }
+                289 if memory[289] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[290]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 291;
+                }
+                291 if memory[291] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[292]).map(|at| memory[at]) else { break };
+                    let b = memory[293];
+                    let Some(to) = checked_address(&memory, memory[294]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 295;
+                }
+                295 if memory[295] == 109 => {
+                    let a = memory[296];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 297;
+                }
+                297 if memory[297] == 2105 => {
+                    let a = memory[298];
+                    let Some(b) = checked_relative(&memory, rb, memory[299]).map(|at| memory[at]) else { break };
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 300;
+                }
+                300 if memory[300] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[301]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 302;
+                }
+                302 if memory[302] == 1105 => {
+                    let a = memory[303];
+                    let b = memory[304];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 305;
+                }
+                305 if memory[305] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[306]).map(|at| memory[at]) else { break };
+                    let b = memory[307];
+                    let Some(to) = checked_address(&memory, memory[308]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 309;
+                }
+                309 if memory[309] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[310]).map(|at| memory[at]) else { break };
+                    let b = memory[311];
+                    let Some(to) = checked_address(&memory, memory[312]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 313;
+                }
+                313 if memory[313] == 109 => {
+                    let a = memory[314];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 315;
+                }
+                315 if memory[315] == 2101 => {
+                    let a = memory[316];
+                    let Some(b) = checked_relative(&memory, rb, memory[317]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_address(&memory, memory[318]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 319;
+                }
+                319 if memory[319] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[320]).map(|at| memory[at]) else { break };
+                    let b = memory[321];
+                    let Some(to) = checked_address(&memory, memory[322]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 323;
+                }
+                323 if memory[323] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[324]).map(|at| memory[at]) else { break };
+                    let b = memory[325];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 326;
+                }
+                326 if memory[326] == 1105 => {
+                    let a = memory[327];
+                    let b = memory[328];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 329;
+                }
+                329 if memory[329] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[330]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 331;
+                }
+                331 if memory[331] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[332]).map(|at| memory[at]) else { break };
+                    let b = memory[333];
+                    let Some(to) = checked_address(&memory, memory[334]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 335;
+                }
+                335 if memory[335] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[336]).map(|at| memory[at]) else { break };
+                    let b = memory[337];
+                    let Some(to) = checked_address(&memory, memory[338]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 339;
+                }
+                339 if memory[339] == 109 => {
+                    let a = memory[340];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 341;
+                }
+                341 if memory[341] == 1206 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[342]).map(|at| memory[at]) else { break };
+                    let b = memory[343];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 344;
+                }
+                344 if memory[344] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[345]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 346;
+                }
+                346 if memory[346] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[347]).map(|at| memory[at]) else { break };
+                    let b = memory[348];
+                    let Some(to) = checked_address(&memory, memory[349]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 350;
+                }
+                350 if memory[350] == 1105 => {
+                    let a = memory[351];
+                    let b = memory[352];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 353;
+                }
+                353 if memory[353] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[354]).map(|at| memory[at]) else { break };
+                    let b = memory[355];
+                    let Some(to) = checked_address(&memory, memory[356]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 357;
+                }
+                357 if memory[357] == 109 => {
+                    let a = memory[358];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 359;
+                }
+                359 if memory[359] == 2108 => {
+                    let a = memory[360];
+                    let Some(b) = checked_relative(&memory, rb, memory[361]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_address(&memory, memory[362]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 363;
+                }
+                363 if memory[363] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[364]).map(|at| memory[at]) else { break };
+                    let b = memory[365];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 366;
+                }
+                366 if memory[366] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[367]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 368;
+                }
+                368 if memory[368] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[369]).map(|at| memory[at]) else { break };
+                    let b = memory[370];
+                    let Some(to) = checked_address(&memory, memory[371]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 372;
+                }
+                372 if memory[372] == 1106 => {
+                    let a = memory[373];
+                    let b = memory[374];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 375;
+                }
+                375 if memory[375] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[376]).map(|at| memory[at]) else { break };
+                    let b = memory[377];
+                    let Some(to) = checked_address(&memory, memory[378]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 379;
+                }
+                379 if memory[379] == 109 => {
+                    let a = memory[380];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 381;
+                }
+                381 if memory[381] == 1207 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[382]).map(|at| memory[at]) else { break };
+                    let b = memory[383];
+                    let Some(to) = checked_address(&memory, memory[384]) else { break };
+                    memory[to] = (a < b) as i64;
+                    pc = 385;
+                }
+                385 if memory[385] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[386]).map(|at| memory[at]) else { break };
+                    let b = memory[387];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 388;
+                }
+                388 if memory[388] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[389]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 390;
+                }
+                390 if memory[390] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[391]).map(|at| memory[at]) else { break };
+                    let b = memory[392];
+                    let Some(to) = checked_address(&memory, memory[393]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 394;
+                }
+                394 if memory[394] == 1105 => {
+                    let a = memory[395];
+                    let b = memory[396];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 397;
+                }
+                397 if memory[397] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[398]).map(|at| memory[at]) else { break };
+                    let b = memory[399];
+                    let Some(to) = checked_address(&memory, memory[400]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 401;
+                }
+                401 if memory[401] == 109 => {
+                    let a = memory[402];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 403;
+                }
+                403 if memory[403] == 2106 => {
+                    let a = memory[404];
+                    let Some(b) = checked_relative(&memory, rb, memory[405]).map(|at| memory[at]) else { break };
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 406;
+                }
+                406 if memory[406] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[407]).map(|at| memory[at]) else { break };
+                    let b = memory[408];
+                    let Some(to) = checked_address(&memory, memory[409]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 410;
+                }
+                410 if memory[410] == 1106 => {
+                    let a = memory[411];
+                    let b = memory[412];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 413;
+                }
+                413 if memory[413] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[414]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 415;
+                }
+                415 if memory[415] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[416]).map(|at| memory[at]) else { break };
+                    let b = memory[417];
+                    let Some(to) = checked_address(&memory, memory[418]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 419;
+                }
+                419 if memory[419] == 109 => {
+                    let a = memory[420];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 421;
+                }
+                421 if memory[421] == 2105 => {
+                    let a = memory[422];
+                    let Some(b) = checked_relative(&memory, rb, memory[423]).map(|at| memory[at]) else { break };
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 424;
+                }
+                424 if memory[424] == 1106 => {
+                    let a = memory[425];
+                    let b = memory[426];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 427;
+                }
+                427 if memory[427] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[428]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 429;
+                }
+                429 if memory[429] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[430]).map(|at| memory[at]) else { break };
+                    let b = memory[431];
+                    let Some(to) = checked_address(&memory, memory[432]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 433;
+                }
+                433 if memory[433] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[434]).map(|at| memory[at]) else { break };
+                    let b = memory[435];
+                    let Some(to) = checked_address(&memory, memory[436]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 437;
+                }
+                437 if memory[437] == 109 => {
+                    let a = memory[438];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 439;
+                }
+                439 if memory[439] == 2108 => {
+                    let a = memory[440];
+                    let Some(b) = checked_relative(&memory, rb, memory[441]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_address(&memory, memory[442]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 443;
+                }
+                443 if memory[443] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[444]).map(|at| memory[at]) else { break };
+                    let b = memory[445];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 446;
+                }
+                446 if memory[446] == 1105 => {
+                    let a = memory[447];
+                    let b = memory[448];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 449;
+                }
+                449 if memory[449] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[450]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 451;
+                }
+                451 if memory[451] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[452]).map(|at| memory[at]) else { break };
+                    let b = memory[453];
+                    let Some(to) = checked_address(&memory, memory[454]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 455;
+                }
+                455 if memory[455] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[456]).map(|at| memory[at]) else { break };
+                    let b = memory[457];
+                    let Some(to) = checked_address(&memory, memory[458]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 459;
+                }
+                459 if memory[459] == 109 => {
+                    let a = memory[460];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 461;
+                }
+                461 if memory[461] == 1202 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[462]).map(|at| memory[at]) else { break };
+                    let b = memory[463];
+                    let Some(to) = checked_address(&memory, memory[464]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 465;
+                }
+                465 if memory[465] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[466]).map(|at| memory[at]) else { break };
+                    let b = memory[467];
+                    let Some(to) = checked_address(&memory, memory[468]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 469;
+                }
+                469 if memory[469] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[470]).map(|at| memory[at]) else { break };
+                    let b = memory[471];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 472;
+                }
+                472 if memory[472] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[473]).map(|at| memory[at]) else { break };
+                    let b = memory[474];
+                    let Some(to) = checked_address(&memory, memory[475]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 476;
+                }
+                476 if memory[476] == 1105 => {
+                    let a = memory[477];
+                    let b = memory[478];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 479;
+                }
+                479 if memory[479] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[480]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 481;
+                }
+                481 if memory[481] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[482]).map(|at| memory[at]) else { break };
+                    let b = memory[483];
+                    let Some(to) = checked_address(&memory, memory[484]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 485;
+                }
+                485 if memory[485] == 109 => {
+                    let a = memory[486];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 487;
+                }
+                487 if memory[487] == 2107 => {
+                    let a = memory[488];
+                    let Some(b) = checked_relative(&memory, rb, memory[489]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_address(&memory, memory[490]) else { break };
+                    memory[to] = (a < b) as i64;
+                    pc = 491;
+                }
+                491 if memory[491] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[492]).map(|at| memory[at]) else { break };
+                    let b = memory[493];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 494;
+                }
+                494 if memory[494] == 1105 => {
+                    let a = memory[495];
+                    let b = memory[496];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 497;
+                }
+                497 if memory[497] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[498]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 499;
+                }
+                499 if memory[499] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[500]).map(|at| memory[at]) else { break };
+                    let b = memory[501];
+                    let Some(to) = checked_address(&memory, memory[502]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 503;
+                }
+                503 if memory[503] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[504]).map(|at| memory[at]) else { break };
+                    let b = memory[505];
+                    let Some(to) = checked_address(&memory, memory[506]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 507;
+                }
+                507 if memory[507] == 109 => {
+                    let a = memory[508];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 509;
+                }
+                509 if memory[509] == 2106 => {
+                    let a = memory[510];
+                    let Some(b) = checked_relative(&memory, rb, memory[511]).map(|at| memory[at]) else { break };
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 512;
+                }
+                512 if memory[512] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[513]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 514;
+                }
+                514 if memory[514] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[515]).map(|at| memory[at]) else { break };
+                    let b = memory[516];
+                    let Some(to) = checked_address(&memory, memory[517]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 518;
+                }
+                518 if memory[518] == 1105 => {
+                    let a = memory[519];
+                    let b = memory[520];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 521;
+                }
+                521 if memory[521] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[522]).map(|at| memory[at]) else { break };
+                    let b = memory[523];
+                    let Some(to) = checked_address(&memory, memory[524]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 525;
+                }
+                525 if memory[525] == 109 => {
+                    let a = memory[526];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 527;
+                }
+                527 if memory[527] == 1208 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[528]).map(|at| memory[at]) else { break };
+                    let b = memory[529];
+                    let Some(to) = checked_address(&memory, memory[530]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 531;
+                }
+                531 if memory[531] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[532]).map(|at| memory[at]) else { break };
+                    let b = memory[533];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 534;
+                }
+                534 if memory[534] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[535]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 536;
+                }
+                536 if memory[536] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[537]).map(|at| memory[at]) else { break };
+                    let b = memory[538];
+                    let Some(to) = checked_address(&memory, memory[539]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 540;
+                }
+                540 if memory[540] == 1106 => {
+                    let a = memory[541];
+                    let b = memory[542];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 543;
+                }
+                543 if memory[543] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[544]).map(|at| memory[at]) else { break };
+                    let b = memory[545];
+                    let Some(to) = checked_address(&memory, memory[546]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 547;
+                }
+                547 if memory[547] == 109 => {
+                    let a = memory[548];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 549;
+                }
+                549 if memory[549] == 21102 => {
+                    let a = memory[550];
+                    let b = memory[551];
+                    let Some(to) = checked_relative(&memory, rb, memory[552]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 553;
+                }
+                553 if memory[553] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[554]).map(|at| memory[at]) else { break };
+                    let b = memory[555];
+                    let Some(to) = checked_address(&memory, memory[556]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 557;
+                }
+                557 if memory[557] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[558]).map(|at| memory[at]) else { break };
+                    let b = memory[559];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 560;
+                }
+                560 if memory[560] == 1105 => {
+                    let a = memory[561];
+                    let b = memory[562];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 563;
+                }
+                563 if memory[563] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[564]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 565;
+                }
+                565 if memory[565] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[566]).map(|at| memory[at]) else { break };
+                    let b = memory[567];
+                    let Some(to) = checked_address(&memory, memory[568]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 569;
+                }
+                569 if memory[569] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[570]).map(|at| memory[at]) else { break };
+                    let b = memory[571];
+                    let Some(to) = checked_address(&memory, memory[572]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 573;
+                }
+                573 if memory[573] == 109 => {
+                    let a = memory[574];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 575;
+                }
+                575 if memory[575] == 1205 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[576]).map(|at| memory[at]) else { break };
+                    let b = memory[577];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 578;
+                }
+                578 if memory[578] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[579]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 580;
+                }
+                580 if memory[580] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[581]).map(|at| memory[at]) else { break };
+                    let b = memory[582];
+                    let Some(to) = checked_address(&memory, memory[583]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 584;
+                }
+                584 if memory[584] == 1106 => {
+                    let a = memory[585];
+                    let b = memory[586];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 587;
+                }
+                587 if memory[587] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[588]).map(|at| memory[at]) else { break };
+                    let b = memory[589];
+                    let Some(to) = checked_address(&memory, memory[590]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 591;
+                }
+                591 if memory[591] == 109 => {
+                    let a = memory[592];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 593;
+                }
+                593 if memory[593] == 1202 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[594]).map(|at| memory[at]) else { break };
+                    let b = memory[595];
+                    let Some(to) = checked_address(&memory, memory[596]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 597;
+                }
+                597 if memory[597] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[598]).map(|at| memory[at]) else { break };
+                    let b = memory[599];
+                    let Some(to) = checked_address(&memory, memory[600]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 601;
+                }
+                601 if memory[601] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[602]).map(|at| memory[at]) else { break };
+                    let b = memory[603];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 604;
+                }
+                604 if memory[604] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[605]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 606;
+                }
+                606 if memory[606] == 1106 => {
+                    let a = memory[607];
+                    let b = memory[608];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 609;
+                }
+                609 if memory[609] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[610]).map(|at| memory[at]) else { break };
+                    let b = memory[611];
+                    let Some(to) = checked_address(&memory, memory[612]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 613;
+                }
+                613 if memory[613] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[614]).map(|at| memory[at]) else { break };
+                    let b = memory[615];
+                    let Some(to) = checked_address(&memory, memory[616]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 617;
+                }
+                617 if memory[617] == 109 => {
+                    let a = memory[618];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 619;
+                }
+                619 if memory[619] == 1201 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[620]).map(|at| memory[at]) else { break };
+                    let b = memory[621];
+                    let Some(to) = checked_address(&memory, memory[622]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 623;
+                }
+                623 if memory[623] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[624]).map(|at| memory[at]) else { break };
+                    let b = memory[625];
+                    let Some(to) = checked_address(&memory, memory[626]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 627;
+                }
+                627 if memory[627] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[628]).map(|at| memory[at]) else { break };
+                    let b = memory[629];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 630;
+                }
+                630 if memory[630] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[631]).map(|at| memory[at]) else { break };
+                    let b = memory[632];
+                    let Some(to) = checked_address(&memory, memory[633]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 634;
+                }
+                634 if memory[634] == 1105 => {
+                    let a = memory[635];
+                    let b = memory[636];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 637;
+                }
+                637 if memory[637] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[638]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 639;
+                }
+                639 if memory[639] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[640]).map(|at| memory[at]) else { break };
+                    let b = memory[641];
+                    let Some(to) = checked_address(&memory, memory[642]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 643;
+                }
+                643 if memory[643] == 109 => {
+                    let a = memory[644];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 645;
+                }
+                645 if memory[645] == 1206 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[646]).map(|at| memory[at]) else { break };
+                    let b = memory[647];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 648;
+                }
+                648 if memory[648] == 1105 => {
+                    let a = memory[649];
+                    let b = memory[650];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 651;
+                }
+                651 if memory[651] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[652]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 653;
+                }
+                653 if memory[653] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[654]).map(|at| memory[at]) else { break };
+                    let b = memory[655];
+                    let Some(to) = checked_address(&memory, memory[656]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 657;
+                }
+                657 if memory[657] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[658]).map(|at| memory[at]) else { break };
+                    let b = memory[659];
+                    let Some(to) = checked_address(&memory, memory[660]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 661;
+                }
+                661 if memory[661] == 109 => {
+                    let a = memory[662];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 663;
+                }
+                663 if memory[663] == 21101 => {
+                    let a = memory[664];
+                    let b = memory[665];
+                    let Some(to) = checked_relative(&memory, rb, memory[666]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 667;
+                }
+                667 if memory[667] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[668]).map(|at| memory[at]) else { break };
+                    let b = memory[669];
+                    let Some(to) = checked_address(&memory, memory[670]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 671;
+                }
+                671 if memory[671] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[672]).map(|at| memory[at]) else { break };
+                    let b = memory[673];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 674;
+                }
+                674 if memory[674] == 1105 => {
+                    let a = memory[675];
+                    let b = memory[676];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 677;
+                }
+                677 if memory[677] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[678]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 679;
+                }
+                679 if memory[679] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[680]).map(|at| memory[at]) else { break };
+                    let b = memory[681];
+                    let Some(to) = checked_address(&memory, memory[682]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 683;
+                }
+                683 if memory[683] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[684]).map(|at| memory[at]) else { break };
+                    let b = memory[685];
+                    let Some(to) = checked_address(&memory, memory[686]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 687;
+                }
+                687 if memory[687] == 109 => {
+                    let a = memory[688];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 689;
+                }
+                689 if memory[689] == 21101 => {
+                    let a = memory[690];
+                    let b = memory[691];
+                    let Some(to) = checked_relative(&memory, rb, memory[692]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 693;
+                }
+                693 if memory[693] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[694]).map(|at| memory[at]) else { break };
+                    let b = memory[695];
+                    let Some(to) = checked_address(&memory, memory[696]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 697;
+                }
+                697 if memory[697] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[698]).map(|at| memory[at]) else { break };
+                    let b = memory[699];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 700;
+                }
+                700 if memory[700] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[701]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 702;
+                }
+                702 if memory[702] == 1106 => {
+                    let a = memory[703];
+                    let b = memory[704];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 705;
+                }
+                705 if memory[705] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[706]).map(|at| memory[at]) else { break };
+                    let b = memory[707];
+                    let Some(to) = checked_address(&memory, memory[708]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 709;
+                }
+                709 if memory[709] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[710]).map(|at| memory[at]) else { break };
+                    let b = memory[711];
+                    let Some(to) = checked_address(&memory, memory[712]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 713;
+                }
+                713 if memory[713] == 109 => {
+                    let a = memory[714];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 715;
+                }
+                715 if memory[715] == 2107 => {
+                    let a = memory[716];
+                    let Some(b) = checked_relative(&memory, rb, memory[717]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_address(&memory, memory[718]) else { break };
+                    memory[to] = (a < b) as i64;
+                    pc = 719;
+                }
+                719 if memory[719] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[720]).map(|at| memory[at]) else { break };
+                    let b = memory[721];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 722;
+                }
+                722 if memory[722] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[723]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 724;
+                }
+                724 if memory[724] == 1106 => {
+                    let a = memory[725];
+                    let b = memory[726];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 727;
+                }
+                727 if memory[727] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[728]).map(|at| memory[at]) else { break };
+                    let b = memory[729];
+                    let Some(to) = checked_address(&memory, memory[730]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 731;
+                }
+                731 if memory[731] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[732]).map(|at| memory[at]) else { break };
+                    let b = memory[733];
+                    let Some(to) = checked_address(&memory, memory[734]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 735;
+                }
+                735 if memory[735] == 109 => {
+                    let a = memory[736];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 737;
+                }
+                737 if memory[737] == 2102 => {
+                    let a = memory[738];
+                    let Some(b) = checked_relative(&memory, rb, memory[739]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_address(&memory, memory[740]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 741;
+                }
+                741 if memory[741] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[742]).map(|at| memory[at]) else { break };
+                    let b = memory[743];
+                    let Some(to) = checked_address(&memory, memory[744]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 745;
+                }
+                745 if memory[745] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[746]).map(|at| memory[at]) else { break };
+                    let b = memory[747];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 748;
+                }
+                748 if memory[748] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[749]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 750;
+                }
+                750 if memory[750] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[751]).map(|at| memory[at]) else { break };
+                    let b = memory[752];
+                    let Some(to) = checked_address(&memory, memory[753]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 754;
+                }
+                754 if memory[754] == 1105 => {
+                    let a = memory[755];
+                    let b = memory[756];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 757;
+                }
+                757 if memory[757] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[758]).map(|at| memory[at]) else { break };
+                    let b = memory[759];
+                    let Some(to) = checked_address(&memory, memory[760]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 761;
+                }
+                761 if memory[761] == 109 => {
+                    let a = memory[762];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 763;
+                }
+                763 if memory[763] == 1201 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[764]).map(|at| memory[at]) else { break };
+                    let b = memory[765];
+                    let Some(to) = checked_address(&memory, memory[766]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 767;
+                }
+                767 if memory[767] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[768]).map(|at| memory[at]) else { break };
+                    let b = memory[769];
+                    let Some(to) = checked_address(&memory, memory[770]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 771;
+                }
+                771 if memory[771] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[772]).map(|at| memory[at]) else { break };
+                    let b = memory[773];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 774;
+                }
+                774 if memory[774] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[775]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 776;
+                }
+                776 if memory[776] == 1105 => {
+                    let a = memory[777];
+                    let b = memory[778];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 779;
+                }
+                779 if memory[779] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[780]).map(|at| memory[at]) else { break };
+                    let b = memory[781];
+                    let Some(to) = checked_address(&memory, memory[782]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 783;
+                }
+                783 if memory[783] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[784]).map(|at| memory[at]) else { break };
+                    let b = memory[785];
+                    let Some(to) = checked_address(&memory, memory[786]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 787;
+                }
+                787 if memory[787] == 109 => {
+                    let a = memory[788];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 789;
+                }
+                789 if memory[789] == 21108 => {
+                    let a = memory[790];
+                    let b = memory[791];
+                    let Some(to) = checked_relative(&memory, rb, memory[792]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 793;
+                }
+                793 if memory[793] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[794]).map(|at| memory[at]) else { break };
+                    let b = memory[795];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 796;
+                }
+                796 if memory[796] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[797]).map(|at| memory[at]) else { break };
+                    let b = memory[798];
+                    let Some(to) = checked_address(&memory, memory[799]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 800;
+                }
+                800 if memory[800] == 1106 => {
+                    let a = memory[801];
+                    let b = memory[802];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 803;
+                }
+                803 if memory[803] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[804]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 805;
+                }
+                805 if memory[805] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[806]).map(|at| memory[at]) else { break };
+                    let b = memory[807];
+                    let Some(to) = checked_address(&memory, memory[808]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 809;
+                }
+                809 if memory[809] == 109 => {
+                    let a = memory[810];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 811;
+                }
+                811 if memory[811] == 21102 => {
+                    let a = memory[812];
+                    let b = memory[813];
+                    let Some(to) = checked_relative(&memory, rb, memory[814]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 815;
+                }
+                815 if memory[815] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[816]).map(|at| memory[at]) else { break };
+                    let b = memory[817];
+                    let Some(to) = checked_address(&memory, memory[818]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 819;
+                }
+                819 if memory[819] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[820]).map(|at| memory[at]) else { break };
+                    let b = memory[821];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 822;
+                }
+                822 if memory[822] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[823]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 824;
+                }
+                824 if memory[824] == 1105 => {
+                    let a = memory[825];
+                    let b = memory[826];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 827;
+                }
+                827 if memory[827] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[828]).map(|at| memory[at]) else { break };
+                    let b = memory[829];
+                    let Some(to) = checked_address(&memory, memory[830]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 831;
+                }
+                831 if memory[831] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[832]).map(|at| memory[at]) else { break };
+                    let b = memory[833];
+                    let Some(to) = checked_address(&memory, memory[834]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 835;
+                }
+                835 if memory[835] == 109 => {
+                    let a = memory[836];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 837;
+                }
+                837 if memory[837] == 21107 => {
+                    let a = memory[838];
+                    let b = memory[839];
+                    let Some(to) = checked_relative(&memory, rb, memory[840]) else { break };
+                    memory[to] = (a < b) as i64;
+                    pc = 841;
+                }
+                841 if memory[841] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[842]).map(|at| memory[at]) else { break };
+                    let b = memory[843];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 844;
+                }
+                844 if memory[844] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[845]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 846;
+                }
+                846 if memory[846] == 1106 => {
+                    let a = memory[847];
+                    let b = memory[848];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 849;
+                }
+                849 if memory[849] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[850]).map(|at| memory[at]) else { break };
+                    let b = memory[851];
+                    let Some(to) = checked_address(&memory, memory[852]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 853;
+                }
+                853 if memory[853] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[854]).map(|at| memory[at]) else { break };
+                    let b = memory[855];
+                    let Some(to) = checked_address(&memory, memory[856]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 857;
+                }
+                857 if memory[857] == 109 => {
+                    let a = memory[858];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 859;
+                }
+                859 if memory[859] == 21108 => {
+                    let a = memory[860];
+                    let b = memory[861];
+                    let Some(to) = checked_relative(&memory, rb, memory[862]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 863;
+                }
+                863 if memory[863] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[864]).map(|at| memory[at]) else { break };
+                    let b = memory[865];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 866;
+                }
+                866 if memory[866] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[867]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 868;
+                }
+                868 if memory[868] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[869]).map(|at| memory[at]) else { break };
+                    let b = memory[870];
+                    let Some(to) = checked_address(&memory, memory[871]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 872;
+                }
+                872 if memory[872] == 1106 => {
+                    let a = memory[873];
+                    let b = memory[874];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 875;
+                }
+                875 if memory[875] == 1002 => {
+                    let Some(a) = checked_address(&memory, memory[876]).map(|at| memory[at]) else { break };
+                    let b = memory[877];
+                    let Some(to) = checked_address(&memory, memory[878]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 879;
+                }
+                879 if memory[879] == 109 => {
+                    let a = memory[880];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 881;
+                }
+                881 if memory[881] == 2101 => {
+                    let a = memory[882];
+                    let Some(b) = checked_relative(&memory, rb, memory[883]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_address(&memory, memory[884]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 885;
+                }
+                885 if memory[885] == 1008 => {
+                    let Some(a) = checked_address(&memory, memory[886]).map(|at| memory[at]) else { break };
+                    let b = memory[887];
+                    let Some(to) = checked_address(&memory, memory[888]) else { break };
+                    memory[to] = (a == b) as i64;
+                    pc = 889;
+                }
+                889 if memory[889] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[890]).map(|at| memory[at]) else { break };
+                    let b = memory[891];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 892;
+                }
+                892 if memory[892] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[893]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 894;
+                }
+                894 if memory[894] == 1001 => {
+                    let Some(a) = checked_address(&memory, memory[895]).map(|at| memory[at]) else { break };
+                    let b = memory[896];
+                    let Some(to) = checked_address(&memory, memory[897]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 898;
+                }
+                898 if memory[898] == 1105 => {
+                    let a = memory[899];
+                    let b = memory[900];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 901;
+                }
+                901 if memory[901] == 4 => {
+                    let Some(a) = checked_address(&memory, memory[902]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 903;
+                }
+                903 if memory[903] == 99 => {
+                    break 'run;
+                }
+                904 if memory[904] == 21102 => {
+                    let a = memory[905];
+                    let b = memory[906];
+                    let Some(to) = checked_relative(&memory, rb, memory[907]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 908;
+                }
+                908 if memory[908] == 21102 => {
+                    let a = memory[909];
+                    let b = memory[910];
+                    let Some(to) = checked_relative(&memory, rb, memory[911]) else { break };
+                    let Some(value) = a.checked_mul(b) else { break };
+                    memory[to] = value;
+                    pc = 912;
+                }
+                912 if memory[912] == 1106 => {
+                    let a = memory[913];
+                    let b = memory[914];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 915;
+                }
+                915 if memory[915] == 21201 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[916]).map(|at| memory[at]) else { break };
+                    let b = memory[917];
+                    let Some(to) = checked_relative(&memory, rb, memory[918]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 919;
+                }
+                919 if memory[919] == 204 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[920]).map(|at| memory[at]) else { break };
+                    if io.write(a).is_none() { break 'run }
+                    pc = 921;
+                }
+                921 if memory[921] == 99 => {
+                    break 'run;
+                }
+                922 if memory[922] == 109 => {
+                    let a = memory[923];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 924;
+                }
+                924 if memory[924] == 1207 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[925]).map(|at| memory[at]) else { break };
+                    let b = memory[926];
+                    let Some(to) = checked_address(&memory, memory[927]) else { break };
+                    memory[to] = (a < b) as i64;
+                    pc = 928;
+                }
+                928 if memory[928] == 1005 => {
+                    let Some(a) = checked_address(&memory, memory[929]).map(|at| memory[at]) else { break };
+                    let b = memory[930];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 931;
+                }
+                931 if memory[931] == 21201 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[932]).map(|at| memory[at]) else { break };
+                    let b = memory[933];
+                    let Some(to) = checked_relative(&memory, rb, memory[934]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 935;
+                }
+                935 if memory[935] == 21101 => {
+                    let a = memory[936];
+                    let b = memory[937];
+                    let Some(to) = checked_relative(&memory, rb, memory[938]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 939;
+                }
+                939 if memory[939] == 1106 => {
+                    let a = memory[940];
+                    let b = memory[941];
+                    if a == 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 942;
+                }
+                942 if memory[942] == 22101 => {
+                    let a = memory[943];
+                    let Some(b) = checked_relative(&memory, rb, memory[944]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_relative(&memory, rb, memory[945]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 946;
+                }
+                946 if memory[946] == 21201 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[947]).map(|at| memory[at]) else { break };
+                    let b = memory[948];
+                    let Some(to) = checked_relative(&memory, rb, memory[949]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 950;
+                }
+                950 if memory[950] == 21101 => {
+                    let a = memory[951];
+                    let b = memory[952];
+                    let Some(to) = checked_relative(&memory, rb, memory[953]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 954;
+                }
+                954 if memory[954] == 1105 => {
+                    let a = memory[955];
+                    let b = memory[956];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 957;
+                }
+                957 if memory[957] == 22201 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[958]).map(|at| memory[at]) else { break };
+                    let Some(b) = checked_relative(&memory, rb, memory[959]).map(|at| memory[at]) else { break };
+                    let Some(to) = checked_relative(&memory, rb, memory[960]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 961;
+                }
+                961 if memory[961] == 1105 => {
+                    let a = memory[962];
+                    let b = memory[963];
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 964;
+                }
+                964 if memory[964] == 21201 => {
+                    let Some(a) = checked_relative(&memory, rb, memory[965]).map(|at| memory[at]) else { break };
+                    let b = memory[966];
+                    let Some(to) = checked_relative(&memory, rb, memory[967]) else { break };
+                    let Some(value) = a.checked_add(b) else { break };
+                    memory[to] = value;
+                    pc = 968;
+                }
+                968 if memory[968] == 109 => {
+                    let a = memory[969];
+                    let Some(value) = rb.checked_add(a) else { break };
+                    rb = value;
+                    pc = 970;
+                }
+                970 if memory[970] == 2105 => {
+                    let a = memory[971];
+                    let Some(b) = checked_relative(&memory, rb, memory[972]).map(|at| memory[at]) else { break };
+                    if a != 0 {
+                        if b < 0 { break }
+                        pc = b as usize;
+                        continue;
+                    }
+                    pc = 973;
+                }
+                _ => break,
+            }
+        }
+        return interpret_from(memory, pc, rb, io);
+    }
+    Ok((memory, io.output()))
+}
@@ -0,0 +1,31 @@
+// programs transpiled to Rust, regenerated with `transpile <day> > src/native/day<day>.rs`
+// and timed against the interpreter by the `native` tool
+#[rustfmt::skip]
+pub mod day9;
+
+#[cfg(test)]
+mod tests {
+    use crate::day9;
+    use crate::vm::{run, transpile, InputOutput, Memory};
+
+    #[test]
+    fn day9_matches_the_interpreter() {
+        for input in [1, 2] {
+            let memory = Memory::from(day9::INPUT);
+            let io = || InputOutput::new(input, Vec::new());
+            let (expected, outputs) = run(memory.clone(), io()).unwrap();
+            let (native, native_outputs) = super::day9::run(memory, io()).unwrap();
+            assert_eq!(outputs, native_outputs, "input {}", input);
+            assert_eq!(expected.to_string(), native.to_string(), "input {}", input);
+        }
+    }
+
+    #[test]
+    fn day9_is_up_to_date() {
+        let source = transpile(&Memory::from(day9::INPUT));
+        assert!(
+            source == include_str!("day9.rs"),
+            "regenerate src/native/day9.rs"
+        );
+    }
+}
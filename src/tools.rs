@@ -0,0 +1,362 @@
+use crate::native;
+use crate::vm::{
+    assemble, control_flow, disassemble, replay, run, run_recorded, run_traced, transpile, AsciiIO,
+    BigInt, Cell, Debugger, Engine, Event, Filter, InputOutput, Machine, Memory, Profile, Recorder,
+    Session, TextTrace, VmError,
+};
+use crate::{day11, day13, day15, day17, day19, day2, day21, day23, day25, day5, day7, day9};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, BufRead, BufWriter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const PROGRAMS: [(usize, &str); 12] = [
+    (2, day2::INPUT),
+    (5, day5::INPUT),
+    (7, day7::INPUT),
+    (9, day9::INPUT),
+    (11, day11::INPUT),
+    (13, day13::INPUT),
+    (15, day15::INPUT),
+    (17, day17::INPUT),
+    (19, day19::INPUT),
+    (21, day21::INPUT),
+    (23, day23::INPUT),
+    (25, day25::INPUT),
+];
+
+// day, memory patches, inputs
+type Workload = (usize, &'static [(usize, i64)], &'static [i64]);
+
+const WORKLOADS: [Workload; 6] = [
+    (2, &[(1, 12), (2, 2)], &[]),
+    (5, &[], &[5]),
+    (9, &[], &[2]),
+    (13, &[], &[]),
+    (17, &[], &[]),
+    (19, &[], &[25, 30]),
+];
+
+type Buffered = InputOutput<VecDeque<i64>, Vec<i64>>;
+type Compiled = fn(Memory, Buffered) -> Result<(Memory, Vec<i64>), VmError>;
+
+// day, transpiled program, inputs
+const NATIVE: [(usize, Compiled, &[i64]); 2] =
+    [(9, native::day9::run, &[1]), (9, native::day9::run, &[2])];
+
+type Tool = fn(&[String]) -> Result<(), String>;
+
+// a day number for the puzzle inputs, otherwise a path to a file
+fn program<C: Cell>(name: &str) -> Result<Memory<C>, String> {
+    match name
+        .parse::<usize>()
+        .ok()
+        .and_then(|day| PROGRAMS.iter().find(|(number, _)| *number == day))
+    {
+        Some((_, input)) => input
+            .parse()
+            .map_err(|error| format!("day {}: {}", name, error)),
+        None => Memory::load(name).map_err(|error| format!("{}: {}", name, error)),
+    }
+}
+
+// the argument at index, or the usage of the tool
+fn argument<'a>(args: &'a [String], index: usize, usage: &str) -> Result<&'a str, String> {
+    args.get(index)
+        .map(String::as_str)
+        .ok_or_else(|| format!("usage: {}", usage))
+}
+
+// the value of an option, or the usage of the tool
+fn option<'a>(value: Option<&'a String>, usage: &str) -> Result<&'a str, String> {
+    value
+        .map(String::as_str)
+        .ok_or_else(|| format!("usage: {}", usage))
+}
+
+fn parse<T: FromStr>(text: &str) -> Result<T, String> {
+    text.trim()
+        .parse()
+        .map_err(|_| format!("invalid value '{}'", text))
+}
+
+fn values<T: FromStr>(text: &str) -> Result<Vec<T>, String> {
+    text.split(',').map(parse).collect()
+}
+
+fn disasm(args: &[String]) -> Result<(), String> {
+    let memory = program(argument(args, 0, "disasm <program>")?)?;
+    print!("{}", disassemble(&memory));
+    Ok(())
+}
+
+// Graphviz source of the control flow graph, e.g. cfg 21 | dot -Tsvg > 21.svg
+fn cfg(args: &[String]) -> Result<(), String> {
+    let memory = program(argument(args, 0, "cfg <program>")?)?;
+    print!("{}", control_flow(&disassemble(&memory)).dot());
+    Ok(())
+}
+
+// Rust source of a module running the program natively, see src/native
+fn transpile_program(args: &[String]) -> Result<(), String> {
+    let memory = program(argument(args, 0, "transpile <program>")?)?;
+    print!("{}", transpile(&memory));
+    Ok(())
+}
+
+fn asm(args: &[String]) -> Result<(), String> {
+    let path = argument(args, 0, "asm <source>")?;
+    let source = fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+    let memory = assemble(&source).map_err(|error| format!("{}: {}", path, error))?;
+    println!("{}", memory);
+    Ok(())
+}
+
+// program input comes from `i` commands only
+fn debug(args: &[String]) -> Result<(), String> {
+    let memory = program(argument(args, 0, "debug <program>")?)?;
+    let mut debugger = Debugger::new(memory, InputOutput::new(VecDeque::new(), Vec::new()));
+    let stdin = io::stdin();
+    debugger
+        .repl(stdin.lock(), io::stdout())
+        .map_err(|error| error.to_string())
+}
+
+// runs with cells of any size and prints the outputs
+fn big(args: &[String]) -> Result<(), String> {
+    const USAGE: &str = "big <program> [-i 1,2,..]";
+    let memory = program::<BigInt>(argument(args, 0, USAGE)?)?;
+    let mut input = match args.get(1).map(String::as_str) {
+        Some("-i") => values::<BigInt>(option(args.get(2), USAGE)?)?,
+        None => Vec::new(),
+        Some(other) => return Err(format!("unknown option {}\nusage: {}", other, USAGE)),
+    }
+    .into_iter();
+    let mut machine = Machine::new(memory);
+    let mut outputs = Vec::new();
+    loop {
+        match machine.resume().map_err(|error| error.to_string())? {
+            Event::NeedsInput => match input.next() {
+                Some(value) => machine.input(value),
+                None => break,
+            },
+            Event::Output(value) => outputs.push(value.to_string()),
+            Event::Halted => break,
+        }
+    }
+    println!("output [{}]", outputs.join(", "));
+    Ok(())
+}
+
+fn trace(args: &[String]) -> Result<(), String> {
+    const USAGE: &str = "trace <program> [-i 1,2,..] [-p from..to] [-o MNEMONIC,..]";
+    let memory = program(argument(args, 0, USAGE)?)?;
+    let stdout = io::stdout();
+    let mut filter = Filter::new(TextTrace::new(BufWriter::new(stdout.lock())));
+    let mut input = VecDeque::new();
+    let mut options = args[1..].iter();
+    while let Some(name) = options.next() {
+        let value = option(options.next(), USAGE)?;
+        match name.as_str() {
+            "-i" => input.extend(values::<i64>(value)?),
+            "-p" => {
+                let (from, to) = value
+                    .split_once("..")
+                    .ok_or_else(|| format!("invalid range '{}'", value))?;
+                filter = filter.pcs(parse(from)?..parse(to)?);
+            }
+            "-o" => {
+                for mnemonic in value.split(',') {
+                    filter = filter.mnemonic(mnemonic);
+                }
+            }
+            other => return Err(format!("unknown option {}\nusage: {}", other, USAGE)),
+        }
+    }
+    let (_, output) = run_traced(memory, InputOutput::new(input, Vec::new()), filter)
+        .map_err(|error| error.to_string())?;
+    println!("output {:?}", output);
+    Ok(())
+}
+
+fn profile(args: &[String]) -> Result<(), String> {
+    const USAGE: &str = "profile <program> [-i 1,2,..] [-l]";
+    let memory = program(argument(args, 0, USAGE)?)?;
+    let mut input = VecDeque::new();
+    let mut listing = false;
+    let mut options = args[1..].iter();
+    while let Some(name) = options.next() {
+        match name.as_str() {
+            "-i" => input.extend(values::<i64>(option(options.next(), USAGE)?)?),
+            "-l" => listing = true,
+            other => return Err(format!("unknown option {}\nusage: {}", other, USAGE)),
+        }
+    }
+    let mut profile = Profile::new();
+    match run_traced(
+        memory.clone(),
+        InputOutput::new(input, Vec::new()),
+        &mut profile,
+    ) {
+        Ok((_, output)) => println!("output {:?}", output),
+        Err(error) => eprintln!("{}", error),
+    }
+    println!();
+    print!("{}", profile);
+    if listing {
+        println!();
+        print!("{}", profile.annotate(&disassemble(&memory)));
+    }
+    Ok(())
+}
+
+// input comes from stdin, one number per line or with -a as ASCII text
+fn record(args: &[String]) -> Result<(), String> {
+    const USAGE: &str = "record <program> <session> [-a]";
+    let memory = program(argument(args, 0, USAGE)?)?;
+    let path = argument(args, 1, USAGE)?;
+    let ascii = match args.get(2).map(String::as_str) {
+        Some("-a") => true,
+        None => false,
+        Some(other) => return Err(format!("unknown option {}\nusage: {}", other, USAGE)),
+    };
+    let recorder = Recorder::create(path).map_err(|error| format!("{}: {}", path, error))?;
+    let stdin = io::stdin();
+    if ascii {
+        run_recorded(memory, AsciiIO::new(stdin.lock(), io::stdout()), recorder)
+            .map_err(|error| error.to_string())?;
+    } else {
+        let mut input = VecDeque::new();
+        for line in stdin.lock().lines() {
+            input.push_back(parse(&line.map_err(|error| error.to_string())?)?);
+        }
+        let (_, output) = run_recorded(memory, InputOutput::new(input, Vec::new()), recorder)
+            .map_err(|error| error.to_string())?;
+        println!("output {:?}", output);
+    }
+    Ok(())
+}
+
+fn replay_session(args: &[String]) -> Result<(), String> {
+    const USAGE: &str = "replay <program> <session>";
+    let memory = program(argument(args, 0, USAGE)?)?;
+    let path = argument(args, 1, USAGE)?;
+    let session = Session::load(path).map_err(|error| format!("{}: {}", path, error))?;
+    let output = replay(memory, &session).map_err(|error| error.to_string())?;
+    println!(
+        "replayed {} records, output {:?}",
+        session.records.len(),
+        output
+    );
+    Ok(())
+}
+
+// name, engine and how to resume; Allocating is the decode the instruction table
+// replaced, so its rate is the before for the Interpreter's after
+type Runner = (
+    &'static str,
+    Engine,
+    fn(&mut Machine) -> Result<Event, VmError>,
+);
+
+const RUNNERS: [Runner; 3] = [
+    (
+        "Allocating",
+        Engine::Interpreter,
+        Machine::resume_allocating,
+    ),
+    ("Interpreter", Engine::Interpreter, Machine::<i64>::resume),
+    ("Threaded", Engine::Threaded, Machine::<i64>::resume),
+];
+
+fn exercise(memory: &Memory, inputs: &[i64], runner: &Runner) -> (Vec<i64>, Machine) {
+    let (_, engine, resume) = runner;
+    let mut machine = Machine::with_engine(memory.clone(), *engine);
+    inputs.iter().for_each(|&value| machine.input(value));
+    let mut outputs = Vec::new();
+    while let Event::Output(value) = resume(&mut machine).unwrap() {
+        outputs.push(value);
+    }
+    (outputs, machine)
+}
+
+// bench [seconds per workload]
+fn bench(args: &[String]) -> Result<(), String> {
+    let seconds = args.first().map_or(Ok(1.0), |arg| parse::<f64>(arg))?;
+    let duration = Duration::try_from_secs_f64(seconds)
+        .map_err(|_| format!("invalid number of seconds {}", seconds))?;
+    for (day, patches, inputs) in WORKLOADS.iter() {
+        let mut memory = program(&day.to_string())?;
+        for &(address, value) in patches.iter() {
+            memory[address] = value;
+        }
+        let (expected, reference) = exercise(&memory, inputs, &RUNNERS[1]);
+        for runner in &RUNNERS {
+            let (outputs, machine) = exercise(&memory, inputs, runner);
+            assert_eq!(expected, outputs, "{}", runner.0);
+            assert_eq!(reference.executed(), machine.executed(), "{}", runner.0);
+            assert_eq!(
+                reference.memory().to_string(),
+                machine.memory().to_string(),
+                "{}",
+                runner.0
+            );
+        }
+        let rates: Vec<String> = RUNNERS
+            .iter()
+            .map(|runner| {
+                let started = Instant::now();
+                let mut executed = 0;
+                while started.elapsed() < duration {
+                    executed += exercise(&memory, inputs, runner).1.executed();
+                }
+                format!(
+                    "{} {:>12.0}",
+                    runner.0,
+                    executed as f64 / started.elapsed().as_secs_f64()
+                )
+            })
+            .collect();
+        println!("day{:<3} instructions/s  {}", day, rates.join("  "));
+    }
+    Ok(())
+}
+
+// native: times each transpiled program against the interpreter, the tests check
+// that they agree
+fn native(_args: &[String]) -> Result<(), String> {
+    for (day, compiled, inputs) in NATIVE.iter() {
+        let memory = program(&day.to_string())?;
+        let io = || InputOutput::new(inputs.iter().copied().collect(), Vec::new());
+        let started = Instant::now();
+        run(memory.clone(), io()).unwrap();
+        let interpreted = started.elapsed();
+        let started = Instant::now();
+        compiled(memory, io()).unwrap();
+        let elapsed = started.elapsed();
+        println!(
+            "day{:<3} inputs {:?}  Interpreter {:>10.3?}  Native {:>10.3?}",
+            day, inputs, interpreted, elapsed
+        );
+    }
+    Ok(())
+}
+
+pub fn get(name: &str) -> Option<Tool> {
+    match name {
+        "asm" => Some(asm),
+        "bench" => Some(bench),
+        "big" => Some(big),
+        "cfg" => Some(cfg),
+        "debug" => Some(debug),
+        "disasm" => Some(disasm),
+        "native" => Some(native),
+        "profile" => Some(profile),
+        "record" => Some(record),
+        "replay" => Some(replay_session),
+        "trace" => Some(trace),
+        "transpile" => Some(transpile_program),
+        _ => None,
+    }
+}
@@ -0,0 +1,160 @@
+use crate::vm::io::{IOResult, IO};
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{BufRead, Write};
+
+const NEWLINE: i64 = 10;
+const MAX_ASCII: i64 = 127;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Ascii {
+    Line(String),
+    // an output outside of ASCII, usually the answer
+    Value(i64),
+}
+
+impl fmt::Display for Ascii {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Ascii::Line(line) => write!(f, "{}", line),
+            Ascii::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+// feeds the program whole lines from a reader and collects what it prints, echoing
+// every completed line to a writer; end of the reader ends the run, and a line that
+// is not ASCII is reported on the writer instead of being sent
+pub struct AsciiIO<R: BufRead, W: Write> {
+    reader: R,
+    writer: W,
+    pending: VecDeque<i64>,
+    line: String,
+    echoed: usize,
+    outputs: Vec<Ascii>,
+}
+
+impl<R: BufRead, W: Write> AsciiIO<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            pending: VecDeque::new(),
+            line: String::new(),
+            echoed: 0,
+            outputs: Vec::new(),
+        }
+    }
+
+    fn end_line(&mut self) {
+        let line = std::mem::take(&mut self.line);
+        writeln!(self.writer, "{}", &line[self.echoed..]).ok();
+        self.echoed = 0;
+        self.outputs.push(Ascii::Line(line));
+    }
+}
+
+impl<R: BufRead, W: Write> IO for AsciiIO<R, W> {
+    type Value = Vec<Ascii>;
+
+    fn read(&mut self) -> IOResult<i64> {
+        if self.pending.is_empty() {
+            // a prompt without a newline would otherwise stay unseen
+            write!(self.writer, "{}", &self.line[self.echoed..]).ok();
+            self.echoed = self.line.len();
+            loop {
+                self.writer.flush().ok();
+                let mut line = String::new();
+                match self.reader.read_line(&mut line) {
+                    Ok(0) | Err(_) => return None,
+                    Ok(_) => (),
+                }
+                let line = line.trim_end_matches(['\r', '\n']);
+                match line.chars().find(|c| !c.is_ascii()) {
+                    Some(c) => writeln!(self.writer, "'{}' is not ASCII, line ignored", c).ok(),
+                    None => break self.pending.extend(line.bytes().map(i64::from)),
+                };
+            }
+            self.pending.push_back(NEWLINE);
+        }
+        self.pending.pop_front()
+    }
+
+    fn write(&mut self, value: i64) -> IOResult<()> {
+        match value {
+            NEWLINE => self.end_line(),
+            0..=MAX_ASCII => self.line.push(value as u8 as char),
+            _ => {
+                if !self.line.is_empty() {
+                    self.end_line();
+                }
+                writeln!(self.writer, "{}", value).ok();
+                self.outputs.push(Ascii::Value(value));
+            }
+        }
+        Some(())
+    }
+
+    fn output(mut self) -> Vec<Ascii> {
+        if !self.line.is_empty() {
+            self.end_line();
+        }
+        self.writer.flush().ok();
+        self.outputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{run, Memory};
+
+    // writes back every value it reads until it reads a zero
+    const ECHO: &str = "3,11,1005,11,6,99,4,11,1105,1,0,0";
+
+    fn echo(input: &str) -> (Vec<Ascii>, String) {
+        let mut written = Vec::new();
+        let (_, outputs) = run(
+            Memory::from(ECHO),
+            AsciiIO::new(input.as_bytes(), &mut written),
+        )
+        .unwrap();
+        (outputs, String::from_utf8(written).unwrap())
+    }
+
+    fn lines(lines: &[&str]) -> Vec<Ascii> {
+        lines
+            .iter()
+            .map(|line| Ascii::Line(line.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn lines_round_trip() {
+        let (outputs, written) = echo("hello\r\n\nworld");
+        assert_eq!(outputs, lines(&["hello", "", "world"]));
+        assert_eq!(written, "hello\n\nworld\n");
+    }
+
+    #[test]
+    fn rejects_lines_that_are_not_ascii() {
+        let (outputs, written) = echo("café\nok\n");
+        assert_eq!(outputs, lines(&["ok"]));
+        assert_eq!(written, "'é' is not ASCII, line ignored\nok\n");
+    }
+
+    #[test]
+    fn large_values_pass_through_as_numbers() {
+        let program = "104,104,104,105,104,1000000,104,128,104,-1,99";
+        let mut written = Vec::new();
+        let io = AsciiIO::new(std::io::empty(), &mut written);
+        let (_, outputs) = run(Memory::from(program), io).unwrap();
+        let mut expected = lines(&["hi"]);
+        expected.extend([Ascii::Value(1_000_000), Ascii::Value(128), Ascii::Value(-1)]);
+        assert_eq!(outputs, expected);
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            "hi\n1000000\n128\n-1\n"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::vm::instructions::*;
+use crate::vm::machine::Memory;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AsmError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl AsmError {
+    fn new(line: usize, message: String) -> Self {
+        Self { line, message }
+    }
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for AsmError {}
+
+enum Value {
+    Number(i64),
+    Label(String),
+}
+
+impl Value {
+    fn parse(text: &str) -> Option<Value> {
+        if let Ok(number) = text.parse() {
+            Some(Value::Number(number))
+        } else if is_identifier(text) {
+            Some(Value::Label(text.to_string()))
+        } else {
+            None
+        }
+    }
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+// (mode, value) of a single operand
+fn operand(text: &str) -> Option<(i64, Value)> {
+    if let Some(value) = text.strip_prefix('#') {
+        return Value::parse(value).map(|value| (1, value));
+    }
+    let inner: String = text
+        .strip_prefix('[')?
+        .strip_suffix(']')?
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if inner == "rb" {
+        Some((2, Value::Number(0)))
+    } else if let Some(offset) = inner
+        .strip_prefix("rb")
+        .filter(|offset| offset.starts_with(['+', '-']))
+    {
+        // parsed with its sign, so that rb-9223372036854775808 fits
+        offset.parse().ok().map(|offset| (2, Value::Number(offset)))
+    } else {
+        Value::parse(&inner).map(|value| (0, value))
+    }
+}
+
+fn find_instruction(mnemonic: &str) -> Option<(i64, Instruction)> {
+    (1..100)
+        .filter_map(|opcode| get_instruction(opcode).map(|instruction| (opcode, *instruction)))
+        .find(|(_, instruction)| instruction.mnemonic.eq_ignore_ascii_case(mnemonic))
+}
+
+fn split_list(text: &str) -> Vec<&str> {
+    if text.trim().is_empty() {
+        Vec::new()
+    } else {
+        text.split(',').map(str::trim).collect()
+    }
+}
+
+fn statement(line: usize, text: &str) -> Result<Vec<Value>, AsmError> {
+    let (mnemonic, rest) = match text.find(char::is_whitespace) {
+        Some(at) => (&text[..at], text[at..].trim()),
+        None => (text, ""),
+    };
+    if mnemonic.eq_ignore_ascii_case("data") {
+        return split_list(rest)
+            .into_iter()
+            .map(|item| {
+                Value::parse(item)
+                    .ok_or_else(|| AsmError::new(line, format!("invalid data value '{}'", item)))
+            })
+            .collect();
+    }
+    let (opcode, instruction) = find_instruction(mnemonic)
+        .ok_or_else(|| AsmError::new(line, format!("unknown mnemonic '{}'", mnemonic)))?;
+    let mut operands = match rest.find("->") {
+        Some(at) if instruction.kind == Kind::Store => {
+            let mut operands = split_list(&rest[..at]);
+            operands.push(rest[at + 2..].trim());
+            operands
+        }
+        Some(_) => {
+            return Err(AsmError::new(
+                line,
+                format!("{} does not store a result", instruction.mnemonic),
+            ))
+        }
+        None if instruction.kind == Kind::Store => {
+            return Err(AsmError::new(
+                line,
+                format!("{} needs a '->' target", instruction.mnemonic),
+            ))
+        }
+        None => split_list(rest),
+    };
+    if operands.len() != instruction.num_params {
+        return Err(AsmError::new(
+            line,
+            format!(
+                "{} takes {} operands, got {}",
+                instruction.mnemonic,
+                instruction.num_params,
+                operands.len()
+            ),
+        ));
+    }
+    let mut encoded = opcode;
+    let mut values = Vec::with_capacity(operands.len());
+    for (index, text) in operands.drain(..).enumerate() {
+        let (mode, value) = operand(text)
+            .ok_or_else(|| AsmError::new(line, format!("invalid operand '{}'", text)))?;
+        if mode == 1 && instruction.kind == Kind::Store && index == instruction.num_params - 1 {
+            return Err(AsmError::new(
+                line,
+                format!("{} cannot store to an immediate", instruction.mnemonic),
+            ));
+        }
+        encoded += mode * 10i64.pow(index as u32 + 2);
+        values.push(value);
+    }
+    values.insert(0, Value::Number(encoded));
+    Ok(values)
+}
+
+pub fn assemble(source: &str) -> Result<Memory, AsmError> {
+    let mut cells: Vec<(usize, Value)> = Vec::new();
+    let mut labels: HashMap<String, usize> = HashMap::new();
+    for (index, line) in source.lines().enumerate() {
+        let number = index + 1;
+        let mut text = line.split(';').next().unwrap().trim();
+        // the address column of a disassembly listing, which has to be where the line
+        // assembles to so that a stray number is not taken for one
+        if let Some(at) = text.find(char::is_whitespace) {
+            let column = &text[..at];
+            if column.chars().all(|c| c.is_ascii_digit()) {
+                if column.parse() != Ok(cells.len()) {
+                    return Err(AsmError::new(
+                        number,
+                        format!(
+                            "address column {} does not match address {}",
+                            column,
+                            cells.len()
+                        ),
+                    ));
+                }
+                text = text[at..].trim_start();
+            }
+        }
+        if let Some(at) = text.find(':') {
+            let label = &text[..at];
+            if !is_identifier(label) {
+                return Err(AsmError::new(number, format!("invalid label '{}'", label)));
+            }
+            if labels.insert(label.to_string(), cells.len()).is_some() {
+                return Err(AsmError::new(
+                    number,
+                    format!("duplicate label '{}'", label),
+                ));
+            }
+            text = text[at + 1..].trim_start();
+        }
+        if !text.is_empty() {
+            cells.extend(
+                statement(number, text)?
+                    .into_iter()
+                    .map(|value| (number, value)),
+            );
+        }
+    }
+    cells
+        .into_iter()
+        .map(|(line, value)| match value {
+            Value::Number(number) => Ok(number),
+            Value::Label(label) => labels
+                .get(&label)
+                .map(|&address| address as i64)
+                .ok_or_else(|| AsmError::new(line, format!("undefined label '{}'", label))),
+        })
+        .collect::<Result<Vec<i64>, AsmError>>()
+        .map(Memory::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::disassemble;
+    use crate::{day11, day13, day15, day17, day19, day2, day21, day23, day25, day5, day7, day9};
+
+    #[test]
+    fn disassembly_assembles_to_the_same_program() {
+        let programs = [
+            day2::INPUT,
+            day5::INPUT,
+            day7::INPUT,
+            day9::INPUT,
+            day11::INPUT,
+            day13::INPUT,
+            day15::INPUT,
+            day17::INPUT,
+            day19::INPUT,
+            day21::INPUT,
+            day23::INPUT,
+            day25::INPUT,
+            "109,-9223372036854775808,204,-9223372036854775808,99",
+        ];
+        for program in programs {
+            let memory = Memory::from(program);
+            let listing = disassemble(&memory).to_string();
+            let assembled = assemble(&listing).unwrap_or_else(|error| panic!("{}", error));
+            assert_eq!(assembled.to_string(), memory.to_string());
+        }
+    }
+
+    #[test]
+    fn address_column_must_match() {
+        let listing = "0 IN -> [5]\n2 OUT [5]\n   4  L4:  HALT\n";
+        assert_eq!(assemble(listing).unwrap().to_string(), "3,5,4,5,99");
+        let cases = [
+            (
+                "12 ADD #1, #2 -> [0]",
+                "address column 12 does not match address 0",
+            ),
+            ("HALT\n0 HALT", "address column 0 does not match address 1"),
+            ("7", "unknown mnemonic '7'"),
+        ];
+        for (source, message) in cases {
+            let error = assemble(source).unwrap_err();
+            assert_eq!(error.message, message, "{}", source);
+            assert_eq!(error.line, source.lines().count());
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::vm::error::VmError;
+use crate::vm::io::{IOResult, Input, Output, IO};
+use crate::vm::machine::{Budget, Event, Machine, Memory};
+use std::collections::VecDeque;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+// instructions run between two yields to the executor
+const YIELD_INTERVAL: u64 = 10_000;
+
+pub trait AsyncIO {
+    type Value;
+    fn poll_read(&mut self, cx: &mut Context) -> Poll<IOResult<i64>>;
+    fn poll_write(&mut self, cx: &mut Context, value: i64) -> Poll<IOResult<()>>;
+    fn output(self) -> Self::Value;
+}
+
+pub trait AsyncInput {
+    fn poll_read(&mut self, cx: &mut Context) -> Poll<IOResult<i64>>;
+}
+
+pub trait AsyncOutput {
+    type Value;
+    fn poll_write(&mut self, cx: &mut Context, value: i64) -> Poll<IOResult<()>>;
+    fn output(self) -> Self::Value;
+}
+
+pub struct AsyncInputOutput<I: AsyncInput, O: AsyncOutput> {
+    input: I,
+    output: O,
+}
+
+impl<I: AsyncInput, O: AsyncOutput> AsyncInputOutput<I, O> {
+    pub fn new(input: I, output: O) -> Self {
+        Self { input, output }
+    }
+}
+
+impl<I: AsyncInput, O: AsyncOutput> AsyncIO for AsyncInputOutput<I, O> {
+    type Value = O::Value;
+
+    fn poll_read(&mut self, cx: &mut Context) -> Poll<IOResult<i64>> {
+        self.input.poll_read(cx)
+    }
+
+    fn poll_write(&mut self, cx: &mut Context, value: i64) -> Poll<IOResult<()>> {
+        self.output.poll_write(cx, value)
+    }
+
+    fn output(self) -> O::Value {
+        self.output.output()
+    }
+}
+
+// a synchronous Input, Output or IO that is always ready; reads and writes that
+// block will block the executor thread
+pub struct Blocking<T>(pub T);
+
+impl<T: Input> AsyncInput for Blocking<T> {
+    fn poll_read(&mut self, _cx: &mut Context) -> Poll<IOResult<i64>> {
+        Poll::Ready(self.0.read())
+    }
+}
+
+impl<T: Output> AsyncOutput for Blocking<T> {
+    type Value = T::Value;
+
+    fn poll_write(&mut self, _cx: &mut Context, value: i64) -> Poll<IOResult<()>> {
+        Poll::Ready(self.0.write(value))
+    }
+
+    fn output(self) -> T::Value {
+        self.0.output()
+    }
+}
+
+impl<T: IO> AsyncIO for Blocking<T> {
+    type Value = T::Value;
+
+    fn poll_read(&mut self, _cx: &mut Context) -> Poll<IOResult<i64>> {
+        Poll::Ready(self.0.read())
+    }
+
+    fn poll_write(&mut self, _cx: &mut Context, value: i64) -> Poll<IOResult<()>> {
+        Poll::Ready(self.0.write(value))
+    }
+
+    fn output(self) -> T::Value {
+        self.0.output()
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    values: VecDeque<i64>,
+    reader: Option<Waker>,
+    closed: bool,
+}
+
+// an unbounded queue between an async writer and reader, usually two machines
+pub fn pipe() -> (PipeWriter, PipeReader) {
+    let shared = Arc::new(Mutex::new(Shared::default()));
+    (
+        PipeWriter {
+            shared: shared.clone(),
+        },
+        PipeReader { shared },
+    )
+}
+
+pub struct PipeWriter {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl AsyncOutput for PipeWriter {
+    type Value = ();
+
+    fn poll_write(&mut self, _cx: &mut Context, value: i64) -> Poll<IOResult<()>> {
+        let mut shared = self.shared.lock().unwrap();
+        if shared.closed {
+            return Poll::Ready(None);
+        }
+        shared.values.push_back(value);
+        if let Some(waker) = shared.reader.take() {
+            waker.wake();
+        }
+        Poll::Ready(Some(()))
+    }
+
+    fn output(self) {}
+}
+
+impl Drop for PipeWriter {
+    fn drop(&mut self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.closed = true;
+        if let Some(waker) = shared.reader.take() {
+            waker.wake();
+        }
+    }
+}
+
+pub struct PipeReader {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl AsyncInput for PipeReader {
+    fn poll_read(&mut self, cx: &mut Context) -> Poll<IOResult<i64>> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.values.pop_front() {
+            Some(value) => Poll::Ready(Some(value)),
+            None if shared.closed => Poll::Ready(None),
+            None => {
+                shared.reader = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for PipeReader {
+    fn drop(&mut self) {
+        self.shared.lock().unwrap().closed = true;
+    }
+}
+
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+// suspends on reads and writes that are not ready, and yields to the executor every
+// YIELD_INTERVAL instructions so that long computations do not starve other tasks
+pub async fn run_async<I: AsyncIO>(
+    memory: Memory,
+    mut io: I,
+) -> Result<(Memory, I::Value), VmError> {
+    let mut machine = Machine::new(memory);
+    loop {
+        let event = match machine.resume_within(&mut Budget::instructions(YIELD_INTERVAL))? {
+            Some(event) => event,
+            None => {
+                YieldNow(false).await;
+                continue;
+            }
+        };
+        let running = match event {
+            Event::NeedsInput => poll_fn(|cx| io.poll_read(cx))
+                .await
+                .map(|value| machine.input(value))
+                .is_some(),
+            Event::Output(value) => poll_fn(|cx| io.poll_write(cx, value)).await.is_some(),
+            Event::Halted => false,
+        };
+        if !running {
+            break;
+        }
+    }
+    Ok((machine.into_memory(), io.output()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day9;
+    use crate::vm::{run, InputOutput, NullIO};
+    use std::task::Wake;
+    use std::thread::{self, Thread};
+
+    const QUINE: &str = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    // reads values and writes each one doubled until its input ends
+    const DOUBLE: &str = "3,11,1002,11,2,11,4,11,1105,1,0,0";
+
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    // the smallest executor: polls on the current thread and parks until woken
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    // polls a before b, until both are done
+    fn join<A: Future, B: Future>(a: A, b: B) -> impl Future<Output = (A::Output, B::Output)> {
+        let (mut a, mut b) = (Box::pin(a), Box::pin(b));
+        let (mut first, mut second) = (None, None);
+        poll_fn(move |cx| {
+            if first.is_none() {
+                if let Poll::Ready(output) = a.as_mut().poll(cx) {
+                    first = Some(output);
+                }
+            }
+            if second.is_none() {
+                if let Poll::Ready(output) = b.as_mut().poll(cx) {
+                    second = Some(output);
+                }
+            }
+            match (first.is_some(), second.is_some()) {
+                (true, true) => Poll::Ready((first.take().unwrap(), second.take().unwrap())),
+                _ => Poll::Pending,
+            }
+        })
+    }
+
+    // counts the reads that had to wait
+    struct Counting(PipeReader, Arc<Mutex<usize>>);
+
+    impl AsyncInput for Counting {
+        fn poll_read(&mut self, cx: &mut Context) -> Poll<IOResult<i64>> {
+            let poll = self.0.poll_read(cx);
+            if poll.is_pending() {
+                *self.1.lock().unwrap() += 1;
+            }
+            poll
+        }
+    }
+
+    #[test]
+    fn blocking_io_runs_day9() {
+        for input in [1, 2] {
+            let (_, expected) = run(
+                Memory::from(day9::INPUT),
+                InputOutput::new(input, Vec::new()),
+            )
+            .unwrap();
+            let io = Blocking(InputOutput::new(input, Vec::new()));
+            let (_, output) = block_on(run_async(Memory::from(day9::INPUT), io)).unwrap();
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn piped_reader_waits_for_its_writer() {
+        let (writer, reader) = pipe();
+        let waits = Arc::new(Mutex::new(0));
+        let reading = run_async(
+            Memory::from(DOUBLE),
+            AsyncInputOutput::new(Counting(reader, waits.clone()), Blocking(Vec::new())),
+        );
+        let writing = run_async(
+            Memory::from(QUINE),
+            AsyncInputOutput::new(Blocking(NullIO::new()), writer),
+        );
+        let (read, written) = block_on(join(reading, writing));
+        let (_, doubled) = read.unwrap();
+        written.unwrap();
+        let quine: Vec<i64> = QUINE
+            .split(',')
+            .map(|value| value.parse().unwrap())
+            .collect();
+        let expected: Vec<i64> = quine.iter().map(|value| value * 2).collect();
+        assert_eq!(doubled, expected);
+        assert!(*waits.lock().unwrap() > 0);
+    }
+
+    #[test]
+    fn dropping_the_writer_ends_the_reader() {
+        let (mut writer, reader) = pipe();
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        for value in [3, 4] {
+            assert_eq!(writer.poll_write(&mut cx, value), Poll::Ready(Some(())));
+        }
+        drop(writer);
+        let io = AsyncInputOutput::new(reader, Blocking(Vec::new()));
+        let (_, output) = block_on(run_async(Memory::from(DOUBLE), io)).unwrap();
+        assert_eq!(output, vec![6, 8]);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::vm::error::VmError;
+use crate::vm::io::InputOutput;
+use crate::vm::machine::{run, Memory};
+use crate::vm::program::Program;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+
+// memory writes made before the program starts, and the values it reads once running
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Job {
+    pub patches: Vec<(usize, i64)>,
+    pub input: Vec<i64>,
+}
+
+impl Job {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn patch(mut self, address: usize, value: i64) -> Self {
+        self.patches.push((address, value));
+        self
+    }
+
+    pub fn input(mut self, values: &[i64]) -> Self {
+        self.input.extend_from_slice(values);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BatchRun {
+    pub output: Vec<i64>,
+    pub memory: Memory,
+}
+
+fn execute(program: &Program, job: &Job) -> Result<BatchRun, VmError> {
+    let mut memory = program.memory();
+    for &(address, value) in &job.patches {
+        memory[address] = value;
+    }
+    let io = InputOutput::new(
+        job.input.iter().copied().collect::<VecDeque<i64>>(),
+        Vec::new(),
+    );
+    let (memory, output) = run(memory, io)?;
+    Ok(BatchRun { output, memory })
+}
+
+// runs every job across all cores; the runs come back paired with their job, in job
+// order
+pub fn batch<I>(program: &Program, jobs: I) -> Result<Vec<(Job, BatchRun)>, VmError>
+where
+    I: IntoIterator<Item = Job>,
+{
+    jobs.into_iter()
+        .collect::<Vec<Job>>()
+        .into_par_iter()
+        .map(|job| execute(program, &job).map(|run| (job, run)))
+        .collect()
+}
+
+// the first run in job order that `found` accepts; runs behind it are abandoned once
+// it is known
+pub fn batch_find<I, F>(
+    program: &Program,
+    jobs: I,
+    found: F,
+) -> Result<Option<(Job, BatchRun)>, VmError>
+where
+    I: IntoIterator<Item = Job>,
+    F: Fn(&Job, &BatchRun) -> bool + Sync,
+{
+    jobs.into_iter()
+        .collect::<Vec<Job>>()
+        .into_par_iter()
+        .map(|job| execute(program, &job).map(|run| (job, run)))
+        .find_first(|result| match result {
+            Ok((job, run)) => found(job, run),
+            Err(_) => true,
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day19, day2};
+
+    #[test]
+    fn finds_the_day2_noun_and_verb() {
+        let program = Program::from(day2::INPUT);
+        let jobs =
+            iproduct!(0..=99, 0..=99).map(|(noun, verb)| Job::new().patch(1, noun).patch(2, verb));
+        let (job, run) = batch_find(&program, jobs, |_, run| run.memory[0] == 19_690_720)
+            .unwrap()
+            .unwrap();
+        assert_eq!(job.patches, vec![(1, 90), (2, 74)]);
+        assert!(job.input.is_empty());
+        assert!(run.output.is_empty());
+    }
+
+    #[test]
+    fn scans_the_day19_beam() {
+        let drone = Program::from(day19::INPUT);
+        let jobs: Vec<Job> = iproduct!(0..50, 0..50)
+            .map(|(x, y)| Job::new().input(&[x, y]))
+            .collect();
+        let runs = batch(&drone, jobs.clone()).unwrap();
+        assert_eq!(
+            runs.iter().map(|(job, _)| job.clone()).collect::<Vec<_>>(),
+            jobs
+        );
+        let affected = runs.iter().filter(|(_, run)| run.output == [1]).count();
+        assert_eq!(affected, 129);
+    }
+}
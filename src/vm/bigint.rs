@@ -0,0 +1,273 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+// decimal digits held by one u32 when printing and parsing
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+
+// a signed integer of any size, enough for ADD, MUL and comparisons on Intcode cells
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BigInt {
+    negative: bool,
+    // base 2^32 digits, least significant first, without leading zeros; zero is empty
+    // and never negative
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+        let low = self.magnitude.first().copied().unwrap_or(0) as u64;
+        let high = self.magnitude.get(1).copied().unwrap_or(0) as u64;
+        let value = high << 32 | low;
+        if self.negative {
+            0i64.checked_sub_unsigned(value)
+        } else {
+            i64::try_from(value).ok()
+        }
+    }
+
+    fn add_small(&mut self, value: u32) {
+        let mut carry = value as u64;
+        for digit in self.magnitude.iter_mut() {
+            if carry == 0 {
+                return;
+            }
+            carry += *digit as u64;
+            *digit = carry as u32;
+            carry >>= 32;
+        }
+        if carry > 0 {
+            self.magnitude.push(carry as u32);
+        }
+    }
+
+    fn mul_small(&mut self, value: u32) {
+        let mut carry = 0;
+        for digit in self.magnitude.iter_mut() {
+            carry += *digit as u64 * value as u64;
+            *digit = carry as u32;
+            carry >>= 32;
+        }
+        if carry > 0 {
+            self.magnitude.push(carry as u32);
+        }
+        *self = Self::new(self.negative, std::mem::take(&mut self.magnitude));
+    }
+
+    // divides the magnitude in place, returning the remainder
+    fn div_small(&mut self, value: u32) -> u32 {
+        let mut remainder = 0;
+        for digit in self.magnitude.iter_mut().rev() {
+            let current = remainder << 32 | *digit as u64;
+            *digit = (current / value as u64) as u32;
+            remainder = current % value as u64;
+        }
+        *self = Self::new(self.negative, std::mem::take(&mut self.magnitude));
+        remainder as u32
+    }
+}
+
+fn compare_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+    for (index, &digit) in long.iter().enumerate() {
+        carry += digit as u64 + short.get(index).copied().unwrap_or(0) as u64;
+        sum.push(carry as u32);
+        carry >>= 32;
+    }
+    sum.push(carry as u32);
+    sum
+}
+
+// a - b for a magnitude a no smaller than b
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (index, &digit) in a.iter().enumerate() {
+        let current = digit as i64 - b.get(index).copied().unwrap_or(0) as i64 - borrow;
+        borrow = (current < 0) as i64;
+        difference.push((current + (borrow << 32)) as u32);
+    }
+    difference
+}
+
+impl std::ops::Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(
+                self.negative,
+                add_magnitudes(&self.magnitude, &other.magnitude),
+            );
+        }
+        match compare_magnitudes(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::new(
+                other.negative,
+                sub_magnitudes(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::new(
+                self.negative,
+                sub_magnitudes(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+}
+
+impl std::ops::Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        let mut product = vec![0u32; self.magnitude.len() + other.magnitude.len()];
+        for (i, &a) in self.magnitude.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.magnitude.iter().enumerate() {
+                carry += a as u64 * b as u64 + product[i + j] as u64;
+                product[i + j] = carry as u32;
+                carry >>= 32;
+            }
+            product[i + other.magnitude.len()] = carry as u32;
+        }
+        BigInt::new(self.negative != other.negative, product)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitudes(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let magnitude = value.unsigned_abs();
+        Self::new(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut rest = BigInt::new(false, self.magnitude.clone());
+        let mut chunks = Vec::new();
+        loop {
+            chunks.push(rest.div_small(DECIMAL_CHUNK));
+            if rest.is_zero() {
+                break;
+            }
+        }
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits += &format!("{:09}", chunk);
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(format!("invalid integer '{}'", text));
+        }
+        let mut value = BigInt::default();
+        for byte in digits.bytes() {
+            value.mul_small(10);
+            value.add_small((byte - b'0') as u32);
+        }
+        Ok(BigInt::new(negative, value.magnitude))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigInt {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn agrees_with_i128_arithmetic() {
+        let values = [
+            0,
+            1,
+            -1,
+            7,
+            -4_294_967_296,
+            4_294_967_295,
+            i64::MAX,
+            i64::MIN,
+            1_000_000_007,
+            -999_999_999_999,
+        ];
+        for a in values {
+            for b in values {
+                let (x, y) = (BigInt::from(a), BigInt::from(b));
+                let wide = |value: i128| big(&value.to_string());
+                assert_eq!(&x + &y, wide(a as i128 + b as i128), "{} + {}", a, b);
+                assert_eq!(&x * &y, wide(a as i128 * b as i128), "{} * {}", a, b);
+                assert_eq!(x.cmp(&y), a.cmp(&b), "{} <=> {}", a, b);
+                assert_eq!((&x + &y).to_i64(), a.checked_add(b), "{} + {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn prints_what_it_parses() {
+        for text in [
+            "0",
+            "-1",
+            "1000000000",
+            "-9223372036854775808",
+            "123456789012345678901234567890123456789",
+        ] {
+            assert_eq!(big(text).to_string(), text);
+        }
+        assert_eq!(big("-0"), BigInt::default());
+        assert_eq!(big("+0012").to_string(), "12");
+        assert_eq!(format!("{:>5}", big("-42")), "  -42");
+        assert!("1-2".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+    }
+}
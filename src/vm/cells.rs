@@ -0,0 +1,168 @@
+use crate::vm::bigint::BigInt;
+use crate::vm::instructions::{Arithmetic, InstructionSet, Level};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
+// what a Machine computes with. i64 follows the machine's Arithmetic, BigInt grows
+// instead of overflowing; either way opcodes, addresses and relative base offsets have
+// to fit an i64
+pub trait Cell:
+    Clone
+    + fmt::Debug
+    + Default
+    + fmt::Display
+    + FromStr
+    + PartialEq
+    + PartialOrd
+    + From<i64>
+    + Send
+    + Sync
+    + 'static
+{
+    // None when the result does not fit the cell
+    fn add(&self, other: &Self, arithmetic: Arithmetic) -> Option<Self>;
+    fn mul(&self, other: &Self, arithmetic: Arithmetic) -> Option<Self>;
+    fn to_i64(&self) -> Option<i64>;
+    // the full instruction set over this cell, shared by every machine using it
+    fn instruction_set() -> Arc<InstructionSet<Self>>;
+}
+
+impl Cell for i64 {
+    #[inline]
+    fn add(&self, other: &Self, arithmetic: Arithmetic) -> Option<Self> {
+        arithmetic.add(*self, *other)
+    }
+
+    #[inline]
+    fn mul(&self, other: &Self, arithmetic: Arithmetic) -> Option<Self> {
+        arithmetic.mul(*self, *other)
+    }
+
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        Some(*self)
+    }
+
+    fn instruction_set() -> Arc<InstructionSet<Self>> {
+        static STANDARD: OnceLock<Arc<InstructionSet<i64>>> = OnceLock::new();
+        STANDARD
+            .get_or_init(|| Arc::new(InstructionSet::new(Level::Day9)))
+            .clone()
+    }
+}
+
+impl Cell for BigInt {
+    fn add(&self, other: &Self, _arithmetic: Arithmetic) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn mul(&self, other: &Self, _arithmetic: Arithmetic) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        BigInt::to_i64(self)
+    }
+
+    fn instruction_set() -> Arc<InstructionSet<Self>> {
+        static STANDARD: OnceLock<Arc<InstructionSet<BigInt>>> = OnceLock::new();
+        STANDARD
+            .get_or_init(|| Arc::new(InstructionSet::new(Level::Day9)))
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day9;
+    use crate::vm::{run, Budget, Engine, Event, InputOutput, Machine, Memory, Profile, VmError};
+
+    const QUINE: &str = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    const SQUARE_MAX: &str = "1102,9223372036854775807,9223372036854775807,7,4,7,99,0";
+
+    // runs until the program halts or asks for input after the given values are used up
+    fn outputs<C: Cell>(machine: &mut Machine<C>, input: &[i64]) -> Result<Vec<C>, VmError> {
+        let mut input = input.iter();
+        let mut outputs = Vec::new();
+        loop {
+            match machine.resume()? {
+                Event::NeedsInput => match input.next() {
+                    Some(&value) => machine.input(C::from(value)),
+                    None => return Ok(outputs),
+                },
+                Event::Output(value) => outputs.push(value),
+                Event::Halted => return Ok(outputs),
+            }
+        }
+    }
+
+    fn big(program: &str) -> Machine<BigInt> {
+        Machine::new(program.parse().unwrap())
+    }
+
+    #[test]
+    fn both_cells_agree_on_day9() {
+        for (program, input) in [(QUINE, 0), (day9::INPUT, 1), (day9::INPUT, 2)] {
+            let io = InputOutput::new(input, Vec::new());
+            let (_, expected) = run(Memory::from(program), io).unwrap();
+            let expected: Vec<BigInt> = expected.into_iter().map(BigInt::from).collect();
+            for engine in [Engine::Interpreter, Engine::Threaded] {
+                let mut machine = Machine::<BigInt>::with_engine(program.parse().unwrap(), engine);
+                assert_eq!(outputs(&mut machine, &[input]).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn big_cells_do_not_overflow() {
+        let mut machine = Machine::new(Memory::from(SQUARE_MAX));
+        assert_eq!(
+            outputs(&mut machine, &[]),
+            Err(VmError::Overflow {
+                pc: 0,
+                opcode: 1102
+            })
+        );
+        let mut machine =
+            Machine::new(Memory::from(SQUARE_MAX)).with_arithmetic(Arithmetic::Wrapping);
+        assert_eq!(outputs(&mut machine, &[]), Ok(vec![1]));
+        let square = "85070591730234615847396907784232501249".parse().unwrap();
+        assert_eq!(outputs(&mut big(SQUARE_MAX), &[]), Ok(vec![square]));
+    }
+
+    #[test]
+    fn wide_opcodes_and_addresses_are_rejected() {
+        let cases = [
+            ("99999999999999999999", None),
+            ("1,99999999999999999999,0,0,99", Some(0)),
+            ("1105,1,99999999999999999999", Some(1)),
+            ("109,99999999999999999999,99", Some(0)),
+        ];
+        for (program, param) in cases {
+            assert_eq!(
+                big(program).resume(),
+                Err(VmError::TooWide { pc: 0, param }),
+                "{}",
+                program
+            );
+        }
+    }
+
+    #[test]
+    fn big_machines_budget_trace_and_snapshot() {
+        let mut machine = big(day9::INPUT);
+        machine.input(BigInt::from(1));
+        let mut budget = Budget::instructions(10);
+        assert_eq!(machine.resume_within(&mut budget), Ok(None));
+        assert_eq!(machine.executed(), 10);
+
+        let mut snapshot = Vec::new();
+        machine.snapshot(&mut snapshot).unwrap();
+        let mut restored = Machine::<BigInt>::restore(snapshot.as_slice()).unwrap();
+        let mut profile = Profile::default();
+        assert_eq!(restored.resume_traced(&mut profile), machine.resume());
+        assert_eq!(profile.total(), restored.executed() - 10);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::vm::disasm::{label, Decoded, Disassembly, Item, Operand};
+use crate::vm::instructions::Kind;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EdgeKind {
+    Next,
+    Jump,
+    Taken,
+    NotTaken,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: EdgeKind,
+}
+
+#[derive(Clone)]
+pub struct Block {
+    pub start: usize,
+    pub code: Vec<Decoded>,
+    // ends in a jump whose target is only known at run time
+    pub indirect: bool,
+    // instructions that store into the block through a position operand
+    pub written_by: Vec<usize>,
+}
+
+impl Block {
+    pub fn last(&self) -> &Decoded {
+        self.code.last().unwrap()
+    }
+
+    // the address right behind the block
+    pub fn end(&self) -> usize {
+        self.last().next()
+    }
+
+    pub fn self_modifying(&self) -> bool {
+        !self.written_by.is_empty()
+    }
+}
+
+pub struct ControlFlow {
+    pub blocks: BTreeMap<usize, Block>,
+    pub edges: Vec<Edge>,
+}
+
+impl ControlFlow {
+    pub fn block(&self, address: usize) -> Option<&Block> {
+        self.blocks
+            .range(..=address)
+            .next_back()
+            .map(|(_, block)| block)
+            .filter(|block| address < block.end())
+    }
+
+    pub fn successors(&self, start: usize) -> Vec<usize> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.from == start)
+            .map(|edge| edge.to)
+            .collect()
+    }
+
+    pub fn predecessors(&self, start: usize) -> Vec<usize> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.to == start)
+            .map(|edge| edge.from)
+            .collect()
+    }
+
+    // Graphviz source; indirect jumps go to a shared `?` node and blocks that the
+    // program writes to are drawn red
+    pub fn dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph intcode {{").unwrap();
+        writeln!(dot, "    node [shape=box, fontname=\"monospace\"];").unwrap();
+        for block in self.blocks.values() {
+            let mut text = format!("{}:\\l", label(block.start));
+            for decoded in &block.code {
+                text.push_str(&format!(
+                    "{:>5}  {}\\l",
+                    decoded.address,
+                    escape(&decoded.to_string())
+                ));
+            }
+            if block.self_modifying() {
+                let writers: Vec<String> = block.written_by.iter().map(usize::to_string).collect();
+                text.push_str(&format!("written by {}\\l", writers.join(", ")));
+            }
+            let color = if block.self_modifying() {
+                ", color=red"
+            } else {
+                ""
+            };
+            writeln!(
+                dot,
+                "    {} [label=\"{}\"{}];",
+                label(block.start),
+                text,
+                color
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Next | EdgeKind::Jump => "",
+                EdgeKind::Taken => " [color=darkgreen]",
+                EdgeKind::NotTaken => " [color=red]",
+            };
+            writeln!(
+                dot,
+                "    {} -> {}{};",
+                label(edge.from),
+                label(edge.to),
+                style
+            )
+            .unwrap();
+        }
+        if self.blocks.values().any(|block| block.indirect) {
+            writeln!(dot, "    indirect [label=\"?\", shape=circle];").unwrap();
+            for block in self.blocks.values().filter(|block| block.indirect) {
+                writeln!(
+                    dot,
+                    "    {} -> indirect [style=dashed];",
+                    label(block.start)
+                )
+                .unwrap();
+            }
+        }
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// splits the reachable code of a disassembly into basic blocks; a block starts at
+// every jump target and behind every jump, halt or gap in the code
+pub fn control_flow(disassembly: &Disassembly) -> ControlFlow {
+    let mut blocks: BTreeMap<usize, Block> = BTreeMap::new();
+    let mut current: Option<Block> = None;
+    for item in &disassembly.items {
+        let decoded = match item {
+            Item::Code(decoded) => decoded,
+            Item::Data(..) => {
+                if let Some(block) = current.take() {
+                    blocks.insert(block.start, block);
+                }
+                continue;
+            }
+        };
+        if disassembly.labels.contains(&decoded.address) {
+            if let Some(block) = current.take() {
+                blocks.insert(block.start, block);
+            }
+        }
+        let block = current.get_or_insert_with(|| Block {
+            start: decoded.address,
+            code: Vec::new(),
+            indirect: false,
+            written_by: Vec::new(),
+        });
+        block.code.push(decoded.clone());
+        if matches!(decoded.instruction.kind, Kind::Jump | Kind::Halt) {
+            block.indirect = decoded.indirect();
+            let block = current.take().unwrap();
+            blocks.insert(block.start, block);
+        }
+    }
+    if let Some(block) = current {
+        blocks.insert(block.start, block);
+    }
+
+    let mut edges = Vec::new();
+    for block in blocks.values() {
+        let last = block.last();
+        for to in last.successors() {
+            if !blocks.contains_key(&to) {
+                continue;
+            }
+            let kind = match (last.instruction.kind, last.taken()) {
+                (Kind::Jump, Some(true)) => EdgeKind::Jump,
+                (Kind::Jump, _) if last.jump_target() == Some(to) => EdgeKind::Taken,
+                (Kind::Jump, _) => EdgeKind::NotTaken,
+                _ => EdgeKind::Next,
+            };
+            edges.push(Edge {
+                from: block.start,
+                to,
+                kind,
+            });
+        }
+    }
+
+    let mut flow = ControlFlow { blocks, edges };
+    // (writer, address written, address of the operand holding it); stores through
+    // a relative operand are not followed, their target depends on rb
+    let stores: Vec<(usize, usize, usize)> = flow
+        .blocks
+        .values()
+        .flat_map(|block| &block.code)
+        .filter(|decoded| decoded.instruction.kind == Kind::Store)
+        .filter_map(|decoded| match decoded.operands.last() {
+            Some(Operand::Position(address)) if *address >= 0 => {
+                Some((decoded.address, *address as usize, decoded.next() - 1))
+            }
+            _ => None,
+        })
+        .collect();
+    // a store whose own target is patched first writes somewhere computed, not to the
+    // placeholder in the code
+    let patched: HashSet<usize> = stores.iter().map(|store| store.1).collect();
+    let stores = stores
+        .into_iter()
+        .filter(|store| !patched.contains(&store.2))
+        .map(|(writer, address, _)| (writer, address));
+    for (writer, address) in stores {
+        if let Some(start) = flow.block(address).map(|block| block.start) {
+            flow.blocks.get_mut(&start).unwrap().written_by.push(writer);
+        }
+    }
+    flow
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{disassemble, Memory};
+
+    // reads a counter, skips to the end when it is 0 and otherwise counts it down
+    const COUNTDOWN: &str = "3,20,1006,20,12,1001,20,-1,20,1005,20,5,99";
+
+    #[test]
+    fn splits_a_branch_and_a_loop() {
+        let flow = control_flow(&disassemble(&Memory::from(COUNTDOWN)));
+        let blocks: Vec<(usize, Vec<usize>)> = flow
+            .blocks
+            .values()
+            .map(|block| {
+                let code = block.code.iter().map(|decoded| decoded.address).collect();
+                (block.start, code)
+            })
+            .collect();
+        assert_eq!(
+            blocks,
+            vec![(0, vec![0, 2]), (5, vec![5, 9]), (12, vec![12])]
+        );
+        assert!(flow
+            .blocks
+            .values()
+            .all(|block| !block.indirect && !block.self_modifying()));
+
+        let edge = |from, to, kind| Edge { from, to, kind };
+        assert_eq!(
+            flow.edges,
+            vec![
+                edge(0, 5, EdgeKind::NotTaken),
+                edge(0, 12, EdgeKind::Taken),
+                edge(5, 12, EdgeKind::NotTaken),
+                edge(5, 5, EdgeKind::Taken),
+            ]
+        );
+        assert_eq!(flow.successors(5), vec![12, 5]);
+        assert_eq!(flow.predecessors(12), vec![0, 5]);
+        assert_eq!(flow.block(10).map(|block| block.start), Some(5));
+        assert!(flow.block(13).is_none());
+
+        let dot = [
+            "digraph intcode {",
+            "    node [shape=box, fontname=\"monospace\"];",
+            "    L0 [label=\"L0:\\l    0  IN -> [20]\\l    2  JZ [20], #12\\l\"];",
+            "    L5 [label=\"L5:\\l    5  ADD [20], #-1 -> [20]\\l    9  JNZ [20], #5\\l\"];",
+            "    L12 [label=\"L12:\\l   12  HALT\\l\"];",
+            "    L0 -> L5 [color=red];",
+            "    L0 -> L12 [color=darkgreen];",
+            "    L5 -> L12 [color=red];",
+            "    L5 -> L5 [color=darkgreen];",
+            "}",
+        ];
+        assert_eq!(flow.dot().lines().collect::<Vec<_>>(), dot);
+    }
+}
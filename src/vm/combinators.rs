@@ -0,0 +1,281 @@
+use crate::vm::io::{IOResult, Input, Output};
+
+// the adapters carry an _input or _output suffix since types like i64 and NullIO are
+// both, and plain map or take would be ambiguous on them
+pub trait InputExt: Input + Sized {
+    // reads from self until it runs out, then from next
+    fn chain<I: Input>(self, next: I) -> Chain<Self, I> {
+        Chain {
+            first: Some(self),
+            second: next,
+        }
+    }
+
+    fn map_input<F: FnMut(i64) -> i64>(self, f: F) -> Mapped<Self, F> {
+        Mapped { inner: self, f }
+    }
+
+    // skips the values the predicate rejects
+    fn filter_input<F: FnMut(i64) -> bool>(self, predicate: F) -> Filtered<Self, F> {
+        Filtered {
+            inner: self,
+            predicate,
+        }
+    }
+
+    fn take_input(self, count: usize) -> Take<Self> {
+        Take {
+            inner: self,
+            remaining: count,
+        }
+    }
+}
+
+impl<I: Input> InputExt for I {}
+
+pub trait OutputExt: Output + Sized {
+    // writes every value to both, the run ends when either refuses one
+    fn tee<O: Output>(self, other: O) -> Tee<Self, O> {
+        Tee {
+            first: self,
+            second: other,
+        }
+    }
+
+    fn map_output<F: FnMut(i64) -> i64>(self, f: F) -> Mapped<Self, F> {
+        Mapped { inner: self, f }
+    }
+
+    // drops the values the predicate rejects
+    fn filter_output<F: FnMut(i64) -> bool>(self, predicate: F) -> Filtered<Self, F> {
+        Filtered {
+            inner: self,
+            predicate,
+        }
+    }
+
+    // ends the run on the write after the first count
+    fn take_output(self, count: usize) -> Take<Self> {
+        Take {
+            inner: self,
+            remaining: count,
+        }
+    }
+}
+
+impl<O: Output> OutputExt for O {}
+
+pub struct Chain<A, B> {
+    first: Option<A>,
+    second: B,
+}
+
+impl<A: Input, B: Input> Input for Chain<A, B> {
+    fn read(&mut self) -> IOResult<i64> {
+        if let Some(first) = self.first.as_mut() {
+            match first.read() {
+                Some(value) => return Some(value),
+                None => self.first = None,
+            }
+        }
+        self.second.read()
+    }
+}
+
+pub struct Tee<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: Output, B: Output> Output for Tee<A, B> {
+    type Value = (A::Value, B::Value);
+
+    fn write(&mut self, value: i64) -> IOResult<()> {
+        let first = self.first.write(value);
+        let second = self.second.write(value);
+        first.and(second)
+    }
+
+    fn output(self) -> Self::Value {
+        (self.first.output(), self.second.output())
+    }
+}
+
+pub struct Mapped<T, F> {
+    inner: T,
+    f: F,
+}
+
+impl<I: Input, F: FnMut(i64) -> i64> Input for Mapped<I, F> {
+    fn read(&mut self) -> IOResult<i64> {
+        self.inner.read().map(&mut self.f)
+    }
+}
+
+impl<O: Output, F: FnMut(i64) -> i64> Output for Mapped<O, F> {
+    type Value = O::Value;
+
+    fn write(&mut self, value: i64) -> IOResult<()> {
+        self.inner.write((self.f)(value))
+    }
+
+    fn output(self) -> O::Value {
+        self.inner.output()
+    }
+}
+
+pub struct Filtered<T, F> {
+    inner: T,
+    predicate: F,
+}
+
+impl<I: Input, F: FnMut(i64) -> bool> Input for Filtered<I, F> {
+    fn read(&mut self) -> IOResult<i64> {
+        loop {
+            let value = self.inner.read()?;
+            if (self.predicate)(value) {
+                return Some(value);
+            }
+        }
+    }
+}
+
+impl<O: Output, F: FnMut(i64) -> bool> Output for Filtered<O, F> {
+    type Value = O::Value;
+
+    fn write(&mut self, value: i64) -> IOResult<()> {
+        if (self.predicate)(value) {
+            self.inner.write(value)
+        } else {
+            Some(())
+        }
+    }
+
+    fn output(self) -> O::Value {
+        self.inner.output()
+    }
+}
+
+pub struct Take<T> {
+    inner: T,
+    remaining: usize,
+}
+
+impl<I: Input> Input for Take<I> {
+    fn read(&mut self) -> IOResult<i64> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        self.inner.read()
+    }
+}
+
+impl<O: Output> Output for Take<O> {
+    type Value = O::Value;
+
+    fn write(&mut self, value: i64) -> IOResult<()> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        self.inner.write(value)
+    }
+
+    fn output(self) -> O::Value {
+        self.inner.output()
+    }
+}
+
+pub struct FromFn<F>(F);
+
+// an input that calls f for every read, e.g. from_fn(move || values.next())
+pub fn from_fn<F: FnMut() -> IOResult<i64>>(f: F) -> FromFn<F> {
+    FromFn(f)
+}
+
+impl<F: FnMut() -> IOResult<i64>> Input for FromFn<F> {
+    fn read(&mut self) -> IOResult<i64> {
+        (self.0)()
+    }
+}
+
+pub struct ToFn<F>(F);
+
+// an output that hands every value to f
+pub fn to_fn<F: FnMut(i64) -> IOResult<()>>(f: F) -> ToFn<F> {
+    ToFn(f)
+}
+
+impl<F: FnMut(i64) -> IOResult<()>> Output for ToFn<F> {
+    type Value = ();
+
+    fn write(&mut self, value: i64) -> IOResult<()> {
+        (self.0)(value)
+    }
+
+    fn output(self) {}
+}
+
+// groups the values into chunks of N, like the (x, y, tile) triples of day13; a
+// chunk left incomplete at the end is dropped
+pub struct Chunked<const N: usize> {
+    partial: Vec<i64>,
+    chunks: Vec<[i64; N]>,
+}
+
+pub fn chunked<const N: usize>() -> Chunked<N> {
+    Chunked {
+        partial: Vec::with_capacity(N),
+        chunks: Vec::new(),
+    }
+}
+
+impl<const N: usize> Chunked<N> {
+    // the chunks completed so far, leaving any partial one
+    pub fn take_chunks(&mut self) -> Vec<[i64; N]> {
+        std::mem::take(&mut self.chunks)
+    }
+}
+
+impl<const N: usize> Output for Chunked<N> {
+    type Value = Vec<[i64; N]>;
+
+    fn write(&mut self, value: i64) -> IOResult<()> {
+        self.partial.push(value);
+        if self.partial.len() == N {
+            let mut chunk = [0; N];
+            chunk.copy_from_slice(&self.partial);
+            self.chunks.push(chunk);
+            self.partial.clear();
+        }
+        Some(())
+    }
+
+    fn output(self) -> Vec<[i64; N]> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{run, InputOutput, Memory, NullIO};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn adapters_work_on_types_that_are_input_and_output() {
+        let mut input = 7.map_input(|value| value * 2).take_input(1);
+        assert_eq!(input.read(), Some(14));
+        assert_eq!(input.read(), None);
+        let mut output = NullIO::new().filter_output(|value| value > 0);
+        assert_eq!(output.write(-1), Some(()));
+    }
+
+    #[test]
+    fn adapters_compose_around_a_run() {
+        // echoes its input until it reads 0
+        let program = Memory::from("3,11,1005,11,6,99,4,11,1105,1,0,0");
+        let input = VecDeque::from([3, -4, 5, 0, 6]);
+        let io = InputOutput::new(
+            input.filter_input(|value| value != -4),
+            Vec::new().map_output(|value| value * 10).take_output(5),
+        );
+        let (_, output) = run(program, io).unwrap();
+        assert_eq!(output, vec![30, 50]);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::vm::disasm::decode;
+use crate::vm::error::VmError;
+use crate::vm::io::IO;
+use crate::vm::machine::{Event, Machine, Memory};
+use std::collections::{BTreeSet, VecDeque};
+use std::io::{self, BufRead, Write};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stop {
+    Stepped,
+    Breakpoint(usize),
+    Watchpoint { address: usize, old: i64, new: i64 },
+    NeedsInput,
+    Closed,
+    Halted,
+}
+
+pub struct Debugger<I: IO> {
+    machine: Machine,
+    io: I,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: BTreeSet<usize>,
+    injected: VecDeque<i64>,
+    outputs: Vec<i64>,
+}
+
+impl<I: IO> Debugger<I> {
+    pub fn new(memory: Memory, io: I) -> Self {
+        Self::attach(Machine::new(memory), io)
+    }
+
+    // takes over a machine where it stands, e.g. one run out of fuel together with its
+    // IO, a restored snapshot or one with another engine or instruction set
+    pub fn attach(machine: Machine, io: I) -> Self {
+        Self {
+            machine,
+            io,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            injected: VecDeque::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    pub fn io(&self) -> &I {
+        &self.io
+    }
+
+    pub fn finish(self) -> (Memory, I::Value) {
+        (self.machine.into_memory(), self.io.output())
+    }
+
+    pub fn toggle_breakpoint(&mut self, pc: usize) -> bool {
+        self.breakpoints.insert(pc) || !self.breakpoints.remove(&pc)
+    }
+
+    pub fn toggle_watchpoint(&mut self, address: usize) -> bool {
+        self.watchpoints.insert(address) || !self.watchpoints.remove(&address)
+    }
+
+    pub fn inject(&mut self, value: i64) {
+        self.injected.push_back(value);
+    }
+
+    pub fn take_outputs(&mut self) -> Vec<i64> {
+        self.outputs.split_off(0)
+    }
+
+    pub fn step(&mut self) -> Result<Stop, VmError> {
+        let watched: Vec<(usize, i64)> = self
+            .watchpoints
+            .iter()
+            .map(|&address| (address, self.machine.memory()[address]))
+            .collect();
+        loop {
+            match self.machine.step()? {
+                None => break,
+                Some(Event::NeedsInput) => {
+                    match self.injected.pop_front().or_else(|| self.io.read()) {
+                        Some(value) => self.machine.input(value),
+                        None => return Ok(Stop::NeedsInput),
+                    }
+                }
+                Some(Event::Output(value)) => {
+                    self.outputs.push(value);
+                    if self.io.write(value).is_none() {
+                        return Ok(Stop::Closed);
+                    }
+                    break;
+                }
+                Some(Event::Halted) => return Ok(Stop::Halted),
+            }
+        }
+        for (address, old) in watched {
+            let new = self.machine.memory()[address];
+            if new != old {
+                return Ok(Stop::Watchpoint { address, old, new });
+            }
+        }
+        Ok(Stop::Stepped)
+    }
+
+    pub fn steps(&mut self, count: usize) -> Result<Stop, VmError> {
+        for _ in 1..count {
+            match self.step()? {
+                Stop::Stepped => continue,
+                stop => return Ok(stop),
+            }
+        }
+        self.step()
+    }
+
+    pub fn cont(&mut self) -> Result<Stop, VmError> {
+        loop {
+            match self.step()? {
+                Stop::Stepped if self.breakpoints.contains(&self.machine.pc()) => {
+                    return Ok(Stop::Breakpoint(self.machine.pc()))
+                }
+                Stop::Stepped => continue,
+                stop => return Ok(stop),
+            }
+        }
+    }
+
+    fn location(&self) -> String {
+        let pc = self.machine.pc();
+        match decode(self.machine.memory(), pc) {
+            Some(decoded) => format!("{:>5}  {}", pc, decoded),
+            None => format!("{:>5}  ?? {}", pc, self.machine.memory()[pc]),
+        }
+    }
+
+    fn command<W: Write>(&mut self, line: &str, output: &mut W) -> io::Result<bool> {
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            Some(command) => command,
+            None => return Ok(true),
+        };
+        let args: Vec<i64> = match words.map(str::parse).collect() {
+            Ok(args) => args,
+            Err(_) => {
+                writeln!(output, "arguments must be integers")?;
+                return Ok(true);
+            }
+        };
+        let stop = match (command, args.as_slice()) {
+            ("s", []) => Some(self.step()),
+            ("s", &[count]) if count > 0 => Some(self.steps(count as usize)),
+            ("c", []) => Some(self.cont()),
+            ("b", &[pc]) if pc >= 0 => {
+                let set = self.toggle_breakpoint(pc as usize);
+                writeln!(
+                    output,
+                    "breakpoint {} {}",
+                    pc,
+                    if set { "set" } else { "cleared" }
+                )?;
+                None
+            }
+            ("w", &[address]) if address >= 0 => {
+                let set = self.toggle_watchpoint(address as usize);
+                writeln!(
+                    output,
+                    "watchpoint {} {}",
+                    address,
+                    if set { "set" } else { "cleared" }
+                )?;
+                None
+            }
+            ("i", values) if !values.is_empty() => {
+                values.iter().for_each(|&value| self.inject(value));
+                None
+            }
+            ("r", []) => {
+                writeln!(output, "pc {} rb {}", self.machine.pc(), self.machine.rb())?;
+                writeln!(output, "{}", self.location())?;
+                None
+            }
+            ("x", &[address]) | ("x", &[address, _]) if address >= 0 => {
+                let length = args.get(1).copied().unwrap_or(1).max(0) as usize;
+                let memory = self.machine.memory();
+                let start = address as usize;
+                for row in (start..start + length).step_by(8) {
+                    let values: Vec<String> = (row..(row + 8).min(start + length))
+                        .map(|address| memory[address].to_string())
+                        .collect();
+                    writeln!(output, "{:>5}  {}", row, values.join(", "))?;
+                }
+                None
+            }
+            ("q", []) => return Ok(false),
+            _ => {
+                writeln!(
+                    output,
+                    "commands: s [n], c, b <pc>, w <addr>, i <value>.., r, x <addr> [len], q"
+                )?;
+                None
+            }
+        };
+        for value in self.take_outputs() {
+            writeln!(output, "output {}", value)?;
+        }
+        match stop {
+            Some(Ok(stop)) => {
+                if stop != Stop::Stepped {
+                    writeln!(output, "{:?}", stop)?;
+                }
+                writeln!(output, "{}", self.location())?;
+            }
+            Some(Err(error)) => writeln!(output, "error: {}", error)?,
+            None => (),
+        }
+        Ok(true)
+    }
+
+    pub fn repl<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        writeln!(output, "{}", self.location())?;
+        for line in input.lines() {
+            if !self.command(&line?, &mut output)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{run_with_budget, Budget, Engine, InputOutput, Outcome};
+
+    // reads values and writes each one doubled until its input ends
+    const DOUBLE: &str = "3,11,1002,11,2,11,4,11,1105,1,0,0";
+
+    fn script(debugger: &mut Debugger<impl IO>, commands: &str) -> String {
+        let mut output = Vec::new();
+        debugger.repl(commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    fn doubling(input: Vec<i64>) -> Debugger<InputOutput<VecDeque<i64>, Vec<i64>>> {
+        let io = InputOutput::new(VecDeque::from(input), Vec::new());
+        Debugger::new(Memory::from(DOUBLE), io)
+    }
+
+    #[test]
+    fn stops_at_breakpoints_and_steps() {
+        let mut debugger = doubling(vec![5, 6]);
+        let output = script(&mut debugger, "b 6\nc\ns\ns 2\nr\nq\ns\n");
+        let expected = [
+            "    0  IN -> [11]",
+            "breakpoint 6 set",
+            "Breakpoint(6)",
+            "    6  OUT [11]",
+            "output 10",
+            "    8  JNZ #1, #0",
+            "    2  MUL [11], #2 -> [11]",
+            "pc 2 rb 0",
+            "    2  MUL [11], #2 -> [11]",
+        ];
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+        assert_eq!(debugger.machine().pc(), 2);
+    }
+
+    #[test]
+    fn stops_on_watched_writes() {
+        let mut debugger = doubling(vec![5]);
+        let output = script(&mut debugger, "w 11\nc\nc\nw 11\nc\n");
+        let expected = [
+            "    0  IN -> [11]",
+            "watchpoint 11 set",
+            "Watchpoint { address: 11, old: 0, new: 5 }",
+            "    2  MUL [11], #2 -> [11]",
+            "Watchpoint { address: 11, old: 5, new: 10 }",
+            "    6  OUT [11]",
+            "watchpoint 11 cleared",
+            "output 10",
+            "NeedsInput",
+            "    0  IN -> [11]",
+        ];
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn examines_memory_and_injects_input() {
+        let mut debugger = doubling(vec![]);
+        let output = script(&mut debugger, "x 8 5\ni 20 21\nc\nx 11\nx -1\n");
+        let expected = [
+            "    0  IN -> [11]",
+            "    8  1105, 1, 0, 0, 0",
+            "output 40",
+            "output 42",
+            "NeedsInput",
+            "    0  IN -> [11]",
+            "   11  42",
+            "commands: s [n], c, b <pc>, w <addr>, i <value>.., r, x <addr> [len], q",
+        ];
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn attaches_to_a_machine_out_of_fuel() {
+        let machine = Machine::with_engine(Memory::from(DOUBLE), Engine::Threaded);
+        let io = InputOutput::new(VecDeque::from(vec![1, 2, 3]), Vec::new());
+        let (machine, io) = match run_with_budget(machine, io, Budget::instructions(6)) {
+            Ok(Outcome::OutOfFuel(machine, io)) => (machine, io),
+            _ => panic!("the budget should run out"),
+        };
+        let mut debugger = Debugger::attach(machine, io);
+        let output = script(&mut debugger, "b 0\nc\nc\n");
+        let expected = [
+            "    6  OUT [11]",
+            "breakpoint 0 set",
+            "output 4",
+            "Breakpoint(0)",
+            "    0  IN -> [11]",
+            "output 6",
+            "Breakpoint(0)",
+            "    0  IN -> [11]",
+        ];
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+        assert_eq!(debugger.finish().1, vec![2, 4, 6]);
+    }
+}
@@ -0,0 +1,300 @@
+use crate::vm::instructions::*;
+use crate::vm::machine::Memory;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+
+const DATA_PER_LINE: usize = 8;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operand {
+    Position(i64),
+    Immediate(i64),
+    Relative(i64),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Position(address) => write!(f, "[{}]", address),
+            Operand::Immediate(value) => write!(f, "#{}", value),
+            Operand::Relative(0) => write!(f, "[rb]"),
+            Operand::Relative(offset) if *offset < 0 => write!(f, "[rb-{}]", offset.unsigned_abs()),
+            Operand::Relative(offset) => write!(f, "[rb+{}]", offset),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Decoded {
+    pub address: usize,
+    pub opcode: i64,
+    pub instruction: Instruction,
+    pub operands: Vec<Operand>,
+}
+
+impl Decoded {
+    pub fn next(&self) -> usize {
+        self.address + 1 + self.operands.len()
+    }
+
+    pub fn jump_target(&self) -> Option<usize> {
+        match (self.instruction.kind, self.operands.last()) {
+            (Kind::Jump, Some(Operand::Immediate(to))) if *to >= 0 => Some(*to as usize),
+            _ => None,
+        }
+    }
+
+    // whether a jump is statically known to be taken or not, from an immediate condition
+    pub fn taken(&self) -> Option<bool> {
+        match self.evaluate()? {
+            InstructionAction::Jump(_) => Some(true),
+            InstructionAction::Noop => Some(false),
+            _ => None,
+        }
+    }
+
+    pub fn constant(&self) -> Option<i64> {
+        match self.evaluate()? {
+            InstructionAction::Store(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn indirect(&self) -> bool {
+        self.instruction.kind == Kind::Jump
+            && self.taken() != Some(false)
+            && self.jump_target().is_none()
+    }
+
+    pub fn successors(&self) -> Vec<usize> {
+        let mut successors = Vec::with_capacity(2);
+        match self.instruction.kind {
+            Kind::Halt => {}
+            Kind::Jump => {
+                let taken = self.taken();
+                if taken != Some(true) {
+                    successors.push(self.next());
+                }
+                if taken != Some(false) {
+                    successors.extend(self.jump_target());
+                }
+            }
+            _ => successors.push(self.next()),
+        }
+        successors
+    }
+
+    // runs the handler if every operand it reads is immediate, with placeholders for
+    // store and jump targets
+    fn evaluate(&self) -> Option<InstructionAction> {
+        let last = self.operands.len().checked_sub(1);
+        let params = self
+            .operands
+            .iter()
+            .enumerate()
+            .map(|(index, operand)| match (self.instruction.kind, operand) {
+                (Kind::Store, _) if Some(index) == last => Some(Param::Positional(0, 0)),
+                (Kind::Jump, _) if Some(index) == last => Some(Param::Immediate(0)),
+                (_, Operand::Immediate(value)) => Some(Param::Immediate(*value)),
+                _ => None,
+            })
+            .collect::<Option<Vec<Param>>>()?;
+        (self.instruction.handler)(&params, Arithmetic::Checked).ok()
+    }
+
+    fn render(&self, labels: &BTreeSet<usize>) -> String {
+        let mut operands: Vec<String> = self.operands.iter().map(Operand::to_string).collect();
+        if let Some(to) = self.jump_target().filter(|to| labels.contains(to)) {
+            *operands.last_mut().unwrap() = format!("#{}", label(to));
+        }
+        let mnemonic = self.instruction.mnemonic;
+        let text = match self.instruction.kind {
+            Kind::Store => {
+                let target = operands.pop().unwrap();
+                if operands.is_empty() {
+                    format!("{} -> {}", mnemonic, target)
+                } else {
+                    format!("{} {} -> {}", mnemonic, operands.join(", "), target)
+                }
+            }
+            _ if operands.is_empty() => mnemonic.to_string(),
+            _ => format!("{} {}", mnemonic, operands.join(", ")),
+        };
+        if self.indirect() {
+            format!("{}  ; indirect", text)
+        } else {
+            text
+        }
+    }
+}
+
+impl fmt::Display for Decoded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(&BTreeSet::new()))
+    }
+}
+
+pub enum Item {
+    Code(Decoded),
+    Data(usize, Vec<i64>),
+}
+
+impl Item {
+    pub fn address(&self) -> usize {
+        match self {
+            Item::Code(decoded) => decoded.address,
+            Item::Data(address, _) => *address,
+        }
+    }
+}
+
+pub struct Disassembly {
+    pub items: Vec<Item>,
+    pub labels: BTreeSet<usize>,
+}
+
+impl Disassembly {
+    pub fn render(&self, item: &Item) -> String {
+        match item {
+            Item::Code(decoded) => decoded.render(&self.labels),
+            Item::Data(_, values) => format!(
+                "data {}",
+                values
+                    .iter()
+                    .map(i64::to_string)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for item in &self.items {
+            let address = item.address();
+            let label = if self.labels.contains(&address) {
+                format!("{}:", label(address))
+            } else {
+                String::new()
+            };
+            writeln!(f, "{:>5}  {:<8}{}", address, label, self.render(item))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn label(address: usize) -> String {
+    format!("L{}", address)
+}
+
+pub fn decode(memory: &Memory, address: usize) -> Option<Decoded> {
+    let op = Op::decode(&InstructionSet::standard(), memory, address).ok()?;
+    let Op {
+        opcode,
+        instruction,
+        modes,
+        values,
+    } = op;
+    let num_params = instruction.num_params;
+    if opcode / (100 * 10i64.pow(num_params as u32)) != 0 || address + num_params >= memory.len() {
+        return None;
+    }
+    let operands = modes
+        .iter()
+        .zip(values)
+        .take(num_params)
+        .map(|(mode, value)| match mode {
+            0 => Some(Operand::Position(value)),
+            1 => Some(Operand::Immediate(value)),
+            2 => Some(Operand::Relative(value)),
+            _ => None,
+        })
+        .collect::<Option<Vec<Operand>>>()?;
+    match (instruction.kind, operands.last()) {
+        (Kind::Store, Some(Operand::Immediate(_))) => None,
+        _ => Some(Decoded {
+            address,
+            opcode,
+            instruction,
+            operands,
+        }),
+    }
+}
+
+// follows control flow from the entry point; a constant that points right behind an
+// unconditional jump is taken to be a return address pushed by a call
+fn reachable(memory: &Memory) -> BTreeMap<usize, Decoded> {
+    let mut code = BTreeMap::new();
+    let mut seen = HashSet::new();
+    let mut constants = HashSet::new();
+    let mut pending = vec![0];
+    while !pending.is_empty() {
+        while let Some(address) = pending.pop() {
+            if !seen.insert(address) {
+                continue;
+            }
+            if let Some(decoded) = decode(memory, address) {
+                pending.extend(decoded.successors());
+                constants.extend(decoded.constant());
+                code.insert(address, decoded);
+            }
+        }
+        pending = code
+            .values()
+            .filter(|decoded| decoded.taken() == Some(true))
+            .map(Decoded::next)
+            .filter(|next| constants.contains(&(*next as i64)) && !seen.contains(next))
+            .collect();
+    }
+    code
+}
+
+pub fn disassemble(memory: &Memory) -> Disassembly {
+    let code = reachable(memory);
+    let targets: BTreeSet<usize> = code.values().filter_map(Decoded::jump_target).collect();
+    let mut items = Vec::new();
+    let mut address = 0;
+    while address < memory.len() {
+        match code.get(&address) {
+            Some(decoded) => {
+                address = decoded.next();
+                items.push(Item::Code(decoded.clone()));
+            }
+            None => {
+                let start = address;
+                let mut values = Vec::with_capacity(DATA_PER_LINE);
+                while address < memory.len()
+                    && values.len() < DATA_PER_LINE
+                    && !code.contains_key(&address)
+                    && (address == start || !targets.contains(&address))
+                {
+                    values.push(memory[address]);
+                    address += 1;
+                }
+                items.push(Item::Data(start, values));
+            }
+        }
+    }
+    let labels = items
+        .iter()
+        .map(Item::address)
+        .filter(|address| targets.contains(address))
+        .collect();
+    Disassembly { items, labels }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_operands_print_their_offset() {
+        assert_eq!(Operand::Relative(0).to_string(), "[rb]");
+        assert_eq!(Operand::Relative(-3).to_string(), "[rb-3]");
+        assert_eq!(
+            Operand::Relative(i64::MIN).to_string(),
+            "[rb-9223372036854775808]"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::vm::instructions::Level;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VmError {
+    UnknownOpcode {
+        pc: usize,
+        opcode: i64,
+    },
+    InvalidMode {
+        pc: usize,
+        opcode: i64,
+        param: usize,
+        mode: i64,
+    },
+    ImmediateStore {
+        pc: usize,
+        opcode: i64,
+        param: usize,
+    },
+    NegativeAddress {
+        pc: usize,
+        opcode: i64,
+        param: usize,
+        address: i64,
+    },
+    AddressOutOfRange {
+        pc: usize,
+        opcode: i64,
+        param: usize,
+        address: usize,
+    },
+    Overflow {
+        pc: usize,
+        opcode: i64,
+    },
+    RequiresLevel {
+        pc: usize,
+        opcode: i64,
+        level: Level,
+    },
+    // a cell used as the opcode, or as an address or offset by param, that does not
+    // fit an i64
+    TooWide {
+        pc: usize,
+        param: Option<usize>,
+    },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmError::UnknownOpcode { pc, opcode } => {
+                write!(f, "unknown opcode {} at pc {}", opcode, pc)
+            }
+            VmError::InvalidMode {
+                pc,
+                opcode,
+                param,
+                mode,
+            } => write!(
+                f,
+                "invalid mode {} for param {} in opcode {} at pc {}",
+                mode, param, opcode, pc
+            ),
+            VmError::ImmediateStore { pc, opcode, param } => write!(
+                f,
+                "param {} of opcode {} at pc {} stores to an immediate",
+                param, opcode, pc
+            ),
+            VmError::NegativeAddress {
+                pc,
+                opcode,
+                param,
+                address,
+            } => write!(
+                f,
+                "param {} of opcode {} at pc {} addresses negative position {}",
+                param, opcode, pc, address
+            ),
+            VmError::AddressOutOfRange {
+                pc,
+                opcode,
+                param,
+                address,
+            } => write!(
+                f,
+                "param {} of opcode {} at pc {} addresses position {} beyond the memory limit",
+                param, opcode, pc, address
+            ),
+            VmError::Overflow { pc, opcode } => {
+                write!(f, "arithmetic overflow in opcode {} at pc {}", opcode, pc)
+            }
+            VmError::RequiresLevel { pc, opcode, level } => write!(
+                f,
+                "opcode {} at pc {} requires the {} instruction set",
+                opcode, pc, level
+            ),
+            VmError::TooWide { pc, param: None } => {
+                write!(f, "opcode at pc {} does not fit an i64", pc)
+            }
+            VmError::TooWide {
+                pc,
+                param: Some(param),
+            } => write!(
+                f,
+                "param {} at pc {} is too wide for an address or offset",
+                param, pc
+            ),
+        }
+    }
+}
+
+impl Error for VmError {}
+
+// raised by instruction handlers, which do not know where they are executing
+pub enum Fault {
+    ImmediateStore(usize),
+    NegativeAddress(usize, i64),
+    Overflow,
+    TooWide(usize),
+}
+
+impl Fault {
+    pub fn at(self, pc: usize, opcode: i64) -> VmError {
+        match self {
+            Fault::ImmediateStore(param) => VmError::ImmediateStore { pc, opcode, param },
+            Fault::NegativeAddress(param, address) => VmError::NegativeAddress {
+                pc,
+                opcode,
+                param,
+                address,
+            },
+            Fault::Overflow => VmError::Overflow { pc, opcode },
+            Fault::TooWide(param) => VmError::TooWide {
+                pc,
+                param: Some(param),
+            },
+        }
+    }
+}
@@ -1,135 +1,498 @@
-pub type Parameters = Vec<Param>;
-type Handler = fn(Parameters) -> InstructionAction;
+use crate::vm::cells::Cell;
+use crate::vm::error::{Fault, VmError};
+use crate::vm::machine::Memory;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
 
-pub struct Instruction {
+pub const MAX_PARAMS: usize = 3;
+
+pub type Parameters<C = i64> = [Param<C>; MAX_PARAMS];
+pub type Handler<C = i64> = fn(&[Param<C>], Arithmetic) -> Result<InstructionAction<C>, Fault>;
+
+// how Machine treats overflow on its i64 cells; a Machine<BigInt> has none
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Arithmetic {
+    #[default]
+    Checked,
+    Wrapping,
+}
+
+impl Arithmetic {
+    // None if the result overflows under checked arithmetic
+    pub fn add(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Arithmetic::Checked => a.checked_add(b),
+            Arithmetic::Wrapping => Some(a.wrapping_add(b)),
+        }
+    }
+
+    pub fn mul(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Arithmetic::Checked => a.checked_mul(b),
+            Arithmetic::Wrapping => Some(a.wrapping_mul(b)),
+        }
+    }
+}
+
+impl FromStr for Arithmetic {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "checked" => Ok(Arithmetic::Checked),
+            "wrapping" => Ok(Arithmetic::Wrapping),
+            _ => Err(format!("unknown arithmetic '{}'", name)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    Plain,
+    Store,
+    Jump,
+    Halt,
+}
+
+#[derive(Debug)]
+pub struct Instruction<C = i64> {
+    pub mnemonic: &'static str,
     pub num_params: usize,
-    pub handler: Handler,
+    pub kind: Kind,
+    pub handler: Handler<C>,
+}
+
+// a derive would ask for C: Copy, which BigInt is not
+impl<C> Clone for Instruction<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-impl Instruction {
-    fn new(num_params: usize, handler: Handler) -> Self {
+impl<C> Copy for Instruction<C> {}
+
+impl<C> Instruction<C> {
+    pub const fn new(
+        mnemonic: &'static str,
+        num_params: usize,
+        kind: Kind,
+        handler: Handler<C>,
+    ) -> Self {
         Self {
+            mnemonic,
             num_params,
+            kind,
             handler,
         }
     }
 }
 
-pub enum Param {
-    Positional(usize, i64),
-    Immediate(i64),
-    Relative(usize, i64),
+// an instruction with its raw modes and operand values, before they are resolved; it
+// holds a copy of its instruction, so it outlives the set it was decoded with
+#[derive(Clone, Debug)]
+pub struct Op<C = i64> {
+    pub opcode: i64,
+    pub instruction: Instruction<C>,
+    pub modes: [i64; MAX_PARAMS],
+    pub values: [C; MAX_PARAMS],
 }
 
-impl Param {
-    fn position(&self) -> usize {
-        match self {
-            Param::Positional(position, _) => *position,
-            Param::Immediate(_) => panic!("immediate values have no position"),
-            Param::Relative(position, _) => *position,
+impl<C: Cell> Op<C> {
+    #[inline(always)]
+    pub fn decode(
+        isa: &InstructionSet<C>,
+        memory: &Memory<C>,
+        pc: usize,
+    ) -> Result<Op<C>, VmError> {
+        let opcode = memory[pc]
+            .to_i64()
+            .ok_or(VmError::TooWide { pc, param: None })?;
+        let instruction = isa.get(opcode).ok_or_else(|| match required(opcode) {
+            Some(level) if level > isa.level => VmError::RequiresLevel { pc, opcode, level },
+            _ => VmError::UnknownOpcode { pc, opcode },
+        })?;
+        let mut op = Op {
+            opcode,
+            instruction: *instruction,
+            modes: [0; MAX_PARAMS],
+            values: Default::default(),
+        };
+        let mut modes = opcode / 100;
+        for index in 0..instruction.num_params {
+            op.modes[index] = modes % 10;
+            op.values[index] = memory[pc + 1 + index].clone();
+            modes /= 10;
+        }
+        if isa.level < Level::Day9 {
+            if let Some(level) = op.modes.iter().filter_map(|&mode| mode_level(mode)).max() {
+                if level > isa.level {
+                    return Err(VmError::RequiresLevel { pc, opcode, level });
+                }
+            }
+        }
+        Ok(op)
+    }
+}
+
+impl Op {
+    // the decode from before the instruction table, with the operands gathered in a
+    // vector and each mode taken with a power of ten, for the standard instructions
+    // only; bench keeps it as the baseline the table-driven decode is measured against
+    pub fn decode_allocating(memory: &Memory, pc: usize) -> Result<Op, VmError> {
+        let opcode = memory[pc];
+        let instruction = *get_instruction(opcode).ok_or(VmError::UnknownOpcode { pc, opcode })?;
+        let operands: Vec<(i64, i64)> = (0..instruction.num_params)
+            .map(|index| {
+                let mode = opcode / 10i64.pow(index as u32 + 2) % 10;
+                (mode, memory[pc + 1 + index])
+            })
+            .collect();
+        let mut op = Op {
+            opcode,
+            instruction,
+            modes: [0; MAX_PARAMS],
+            values: [0; MAX_PARAMS],
+        };
+        for (index, (mode, value)) in operands.into_iter().enumerate() {
+            op.modes[index] = mode;
+            op.values[index] = value;
         }
+        Ok(op)
     }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Param<C = i64> {
+    Positional(usize, C),
+    Immediate(C),
+    Relative(usize, C),
+}
 
-    fn value(&self) -> i64 {
+impl<C> Param<C> {
+    pub fn value(&self) -> &C {
         match self {
-            Param::Positional(_, value) => *value,
-            Param::Immediate(value) => *value,
-            Param::Relative(_, value) => *value,
+            Param::Positional(_, value) => value,
+            Param::Immediate(value) => value,
+            Param::Relative(_, value) => value,
         }
     }
 }
 
-fn math(params: Parameters, op: fn(i64, i64) -> i64) -> InstructionAction {
-    InstructionAction::Store(
-        params[2].position(),
-        op(params[0].value(), params[1].value()),
-    )
+pub fn position<C>(params: &[Param<C>], index: usize) -> Result<usize, Fault> {
+    match params[index] {
+        Param::Positional(position, _) => Ok(position),
+        Param::Immediate(_) => Err(Fault::ImmediateStore(index)),
+        Param::Relative(position, _) => Ok(position),
+    }
+}
+
+pub fn address<C: Cell>(params: &[Param<C>], index: usize) -> Result<usize, Fault> {
+    match params[index].value().to_i64() {
+        Some(value) if value < 0 => Err(Fault::NegativeAddress(index, value)),
+        Some(value) => Ok(value as usize),
+        None => Err(Fault::TooWide(index)),
+    }
+}
+
+fn math<C: Cell>(
+    params: &[Param<C>],
+    op: fn(&C, &C, Arithmetic) -> Option<C>,
+    arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    Ok(InstructionAction::Store(
+        position(params, 2)?,
+        op(params[0].value(), params[1].value(), arithmetic).ok_or(Fault::Overflow)?,
+    ))
 }
 
-fn add(params: Parameters) -> InstructionAction {
-    math(params, |a, b| a + b)
+fn add<C: Cell>(
+    params: &[Param<C>],
+    arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    math(params, C::add, arithmetic)
 }
 
-fn mul(params: Parameters) -> InstructionAction {
-    math(params, |a, b| a * b)
+fn mul<C: Cell>(
+    params: &[Param<C>],
+    arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    math(params, C::mul, arithmetic)
 }
 
-fn read(params: Parameters) -> InstructionAction {
-    InstructionAction::Read(params[0].position())
+fn read<C: Cell>(
+    params: &[Param<C>],
+    _arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    Ok(InstructionAction::Read(position(params, 0)?))
 }
 
-fn write(params: Parameters) -> InstructionAction {
-    InstructionAction::Write(params[0].value())
+fn write<C: Cell>(
+    params: &[Param<C>],
+    _arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    Ok(InstructionAction::Write(params[0].value().clone()))
 }
 
-fn jnz(params: Parameters) -> InstructionAction {
-    if params[0].value() != 0 {
-        InstructionAction::Jump(params[1].value() as usize)
+fn jnz<C: Cell>(
+    params: &[Param<C>],
+    _arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    if *params[0].value() != C::default() {
+        Ok(InstructionAction::Jump(address(params, 1)?))
     } else {
-        InstructionAction::Noop
+        Ok(InstructionAction::Noop)
     }
 }
 
-fn jz(params: Parameters) -> InstructionAction {
-    if params[0].value() == 0 {
-        InstructionAction::Jump(params[1].value() as usize)
+fn jz<C: Cell>(
+    params: &[Param<C>],
+    _arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    if *params[0].value() == C::default() {
+        Ok(InstructionAction::Jump(address(params, 1)?))
     } else {
-        InstructionAction::Noop
+        Ok(InstructionAction::Noop)
     }
 }
 
-fn lt(params: Parameters) -> InstructionAction {
-    InstructionAction::Store(
-        params[2].position(),
-        if params[0].value() < params[1].value() {
+fn lt<C: Cell>(
+    params: &[Param<C>],
+    _arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    Ok(InstructionAction::Store(
+        position(params, 2)?,
+        C::from(if params[0].value() < params[1].value() {
             1
         } else {
             0
-        },
-    )
+        }),
+    ))
 }
 
-fn eq(params: Parameters) -> InstructionAction {
-    InstructionAction::Store(
-        params[2].position(),
-        if params[0].value() == params[1].value() {
+fn eq<C: Cell>(
+    params: &[Param<C>],
+    _arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    Ok(InstructionAction::Store(
+        position(params, 2)?,
+        C::from(if params[0].value() == params[1].value() {
             1
         } else {
             0
-        },
-    )
+        }),
+    ))
 }
 
-fn rel(params: Parameters) -> InstructionAction {
-    InstructionAction::ChangeRelativeBase(params[0].value())
+fn rel<C: Cell>(
+    params: &[Param<C>],
+    _arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    let by = params[0].value().to_i64().ok_or(Fault::TooWide(0))?;
+    Ok(InstructionAction::ChangeRelativeBase(by))
 }
 
-fn halt(_params: Parameters) -> InstructionAction {
-    InstructionAction::Halt
+fn halt<C: Cell>(
+    _params: &[Param<C>],
+    _arithmetic: Arithmetic,
+) -> Result<InstructionAction<C>, Fault> {
+    Ok(InstructionAction::Halt)
 }
 
-pub enum InstructionAction {
-    Store(usize, i64),
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InstructionAction<C = i64> {
+    Store(usize, C),
     Read(usize),
-    Write(i64),
+    Write(C),
     Jump(usize),
     ChangeRelativeBase(i64),
     Noop,
     Halt,
 }
 
-pub fn get_instruction(opcode: i64) -> Instruction {
-    match opcode % 100 {
-        1 => Instruction::new(3, add),
-        2 => Instruction::new(3, mul),
-        3 => Instruction::new(1, read),
-        4 => Instruction::new(1, write),
-        5 => Instruction::new(2, jnz),
-        6 => Instruction::new(2, jz),
-        7 => Instruction::new(3, lt),
-        8 => Instruction::new(3, eq),
-        9 => Instruction::new(1, rel),
-        99 => Instruction::new(0, halt),
-        n => panic!("Unsupported opcode {} ({})", n, opcode),
+// the instruction sets of the puzzles that introduced them, with their parameter modes
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Day2,
+    Day5,
+    Day9,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Level::Day2 => write!(f, "day2"),
+            Level::Day5 => write!(f, "day5"),
+            Level::Day9 => write!(f, "day9"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "day2" => Ok(Level::Day2),
+            "day5" => Ok(Level::Day5),
+            "day9" => Ok(Level::Day9),
+            _ => Err(format!("unknown level '{}'", name)),
+        }
+    }
+}
+
+// the level that introduced a standard opcode
+fn required(opcode: i64) -> Option<Level> {
+    match opcode.checked_rem(100)? {
+        1 | 2 | 99 => Some(Level::Day2),
+        3..=8 => Some(Level::Day5),
+        9 => Some(Level::Day9),
+        _ => None,
+    }
+}
+
+fn mode_level(mode: i64) -> Option<Level> {
+    match mode {
+        0 => Some(Level::Day2),
+        1 => Some(Level::Day5),
+        2 => Some(Level::Day9),
+        _ => None,
+    }
+}
+
+// the standard instructions over any cell
+const fn instructions<C: Cell>() -> [Option<Instruction<C>>; 100] {
+    let mut table = [None; 100];
+    table[1] = Some(Instruction::new("ADD", 3, Kind::Store, add::<C>));
+    table[2] = Some(Instruction::new("MUL", 3, Kind::Store, mul::<C>));
+    table[3] = Some(Instruction::new("IN", 1, Kind::Store, read::<C>));
+    table[4] = Some(Instruction::new("OUT", 1, Kind::Plain, write::<C>));
+    table[5] = Some(Instruction::new("JNZ", 2, Kind::Jump, jnz::<C>));
+    table[6] = Some(Instruction::new("JZ", 2, Kind::Jump, jz::<C>));
+    table[7] = Some(Instruction::new("LT", 3, Kind::Store, lt::<C>));
+    table[8] = Some(Instruction::new("EQ", 3, Kind::Store, eq::<C>));
+    table[9] = Some(Instruction::new("ARB", 1, Kind::Plain, rel::<C>));
+    table[99] = Some(Instruction::new("HALT", 0, Kind::Halt, halt::<C>));
+    table
+}
+
+static INSTRUCTIONS: [Option<Instruction>; 100] = instructions::<i64>();
+
+pub fn get_instruction(opcode: i64) -> Option<&'static Instruction> {
+    if opcode < 0 {
+        None
+    } else {
+        INSTRUCTIONS[(opcode % 100) as usize].as_ref()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InstructionSet<C = i64> {
+    table: [Option<Instruction<C>>; 100],
+    level: Level,
+    // false once an opcode was registered or removed
+    standard: bool,
+}
+
+impl<C: Cell> InstructionSet<C> {
+    pub fn new(level: Level) -> Self {
+        let mut table = instructions::<C>();
+        for (opcode, instruction) in table.iter_mut().enumerate() {
+            if !required(opcode as i64).is_some_and(|required| required <= level) {
+                *instruction = None;
+            }
+        }
+        Self {
+            table,
+            level,
+            standard: true,
+        }
+    }
+
+    // the full instruction set, shared by every machine that does not ask for another
+    pub fn standard() -> Arc<Self> {
+        C::instruction_set()
+    }
+
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    // exactly the standard set of its level, which can be rebuilt from the level alone
+    pub fn is_standard(&self) -> bool {
+        self.standard
+    }
+
+    // adds or replaces an opcode, returning what it replaced
+    pub fn register(&mut self, opcode: i64, instruction: Instruction<C>) -> Option<Instruction<C>> {
+        assert!(
+            (1..100).contains(&opcode),
+            "opcode {} is not in 1..100",
+            opcode
+        );
+        // decoded instructions hold their operands in a fixed array
+        assert!(
+            instruction.num_params <= MAX_PARAMS,
+            "{} takes {} parameters, at most {} are supported",
+            instruction.mnemonic,
+            instruction.num_params,
+            MAX_PARAMS
+        );
+        self.standard = false;
+        self.table[opcode as usize].replace(instruction)
+    }
+
+    pub fn remove(&mut self, opcode: i64) -> Option<Instruction<C>> {
+        self.standard = false;
+        self.table.get_mut(opcode as usize)?.take()
+    }
+
+    #[inline]
+    pub fn get(&self, opcode: i64) -> Option<&Instruction<C>> {
+        if opcode < 0 {
+            None
+        } else {
+            self.table[(opcode % 100) as usize].as_ref()
+        }
+    }
+}
+
+impl<C: Cell> Default for InstructionSet<C> {
+    fn default() -> Self {
+        Self::new(Level::Day9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{Event, Machine};
+
+    fn double(params: &[Param], _arithmetic: Arithmetic) -> Result<InstructionAction, Fault> {
+        Ok(InstructionAction::Store(
+            position(params, 1)?,
+            params[0].value() * 2,
+        ))
+    }
+
+    #[test]
+    fn machines_share_a_custom_set() {
+        let mut isa: InstructionSet = InstructionSet::default();
+        isa.register(42, Instruction::new("DBL", 2, Kind::Store, double));
+        let isa = Arc::new(isa);
+        for input in [3, -8] {
+            let memory = Memory::from("3,9,42,9,9,4,9,99,0,0");
+            let mut machine = Machine::new(memory).with_instruction_set(isa.clone());
+            machine.input(input);
+            assert_eq!(machine.resume().unwrap(), Event::Output(input * 2));
+        }
+        assert_eq!(Arc::strong_count(&isa), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 3 are supported")]
+    fn register_rejects_too_many_parameters() {
+        let mut isa: InstructionSet = InstructionSet::default();
+        isa.register(42, Instruction::new("WIDE", 4, Kind::Plain, halt));
     }
 }
@@ -171,7 +171,5 @@ impl Output for Stdout {
         self.lock().write_all(&[value as u8]).ok()
     }
 
-    fn output(self) -> Self::Value {
-        ()
-    }
+    fn output(self) -> Self::Value {}
 }
@@ -0,0 +1,141 @@
+use crate::vm::cells::Cell;
+use crate::vm::machine::Memory;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+const COMMENT: char = '#';
+
+// a value that is not a number; index is the address it would have been loaded to
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoadError {
+    pub index: usize,
+    pub line: usize,
+    pub text: String,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.text.is_empty() {
+            write!(
+                f,
+                "line {}: missing value at index {}",
+                self.line, self.index
+            )
+        } else {
+            write!(
+                f,
+                "line {}: invalid value '{}' at index {}",
+                self.line, self.text, self.index
+            )
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+impl From<LoadError> for io::Error {
+    fn from(error: LoadError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
+
+// comma separated values over any number of lines, with `#` comments up to the end
+// of a line; a single trailing comma is allowed
+impl<C: Cell> FromStr for Memory<C> {
+    type Err = LoadError;
+
+    fn from_str(program: &str) -> Result<Self, LoadError> {
+        let code = program
+            .lines()
+            .map(|line| line.split(COMMENT).next().unwrap())
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let tokens: Vec<&str> = code.split(',').collect();
+        let mut values = Vec::with_capacity(tokens.len());
+        let mut line = 1;
+        for (index, token) in tokens.iter().enumerate() {
+            let text = token.trim();
+            let leading = &token[..token.len() - token.trim_start().len()];
+            let start = line + leading.matches('\n').count();
+            line += token.matches('\n').count();
+            match text.parse() {
+                Ok(value) => values.push(value),
+                Err(_) if text.is_empty() && index + 1 == tokens.len() => (),
+                Err(_) => {
+                    return Err(LoadError {
+                        index,
+                        line: start,
+                        text: text.to_string(),
+                    })
+                }
+            }
+        }
+        Ok(Memory::new(values))
+    }
+}
+
+impl<C: Cell> Memory<C> {
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut program = String::new();
+        reader.read_to_string(&mut program)?;
+        Ok(program.parse()?)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(fs::read_to_string(path)?.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(program: &str) -> Result<String, LoadError> {
+        program.parse::<Memory>().map(|memory| memory.to_string())
+    }
+
+    fn error(index: usize, line: usize, text: &str) -> Result<String, LoadError> {
+        Err(LoadError {
+            index,
+            line,
+            text: text.to_string(),
+        })
+    }
+
+    #[test]
+    fn loads_lines_comments_and_a_trailing_comma() {
+        assert_eq!(load("1,2,3"), Ok("1,2,3".to_string()));
+        assert_eq!(load(" 1, -2 ,3,\n"), Ok("1,-2,3".to_string()));
+        let program = "# header\n1,0,0,0, # add\n\n99 # halt\n";
+        assert_eq!(load(program), Ok("1,0,0,0,99".to_string()));
+    }
+
+    #[test]
+    fn reports_index_line_and_text() {
+        assert_eq!(load("1,x,3"), error(1, 1, "x"));
+        assert_eq!(load("1,2,\n3,\n\n  4x,5"), error(3, 4, "4x"));
+        assert_eq!(load("1,2 # 3,x\n,y"), error(2, 2, "y"));
+        assert_eq!(load("1,2 3,4"), error(1, 1, "2 3"));
+        assert_eq!(load("1,\n2\n3"), error(1, 2, "2\n3"));
+    }
+
+    #[test]
+    fn reports_empty_values() {
+        assert_eq!(load("1,,3"), error(1, 1, ""));
+        assert_eq!(load("1,2,,"), error(2, 1, ""));
+        assert_eq!(load(",1"), error(0, 1, ""));
+        assert_eq!(load(""), Ok(String::new()));
+        assert_eq!(
+            error(1, 3, "").unwrap_err().to_string(),
+            "line 3: missing value at index 1"
+        );
+        assert_eq!(
+            error(4, 2, "2 3").unwrap_err().to_string(),
+            "line 2: invalid value '2 3' at index 4"
+        );
+    }
+}
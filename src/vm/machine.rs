@@ -1,115 +1,816 @@
+use crate::vm::cells::Cell;
+use crate::vm::error::VmError;
 use crate::vm::instructions::*;
 use crate::vm::io::IO;
+use crate::vm::threaded::{Code, Engine};
+use crate::vm::trace::{Effect, NullTrace, Trace, TraceEvent};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
 use std::ops::{Index, IndexMut};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+const PAGE_SIZE: usize = 1024;
+// writes up to this far past the dense part grow it, further ones go to sparse pages
+const DENSE_SLACK: usize = 64 * PAGE_SIZE;
+
+// PAGE_SIZE cells
+type Page<C> = Box<[C]>;
+
+// the program image and anything written near it live in one vector; scattered far
+// writes only cost a page each. A memory spawned from a shared image reads through to
+// it, and copies it into the vector only as far as the page of the highest write
 #[derive(Clone, Debug)]
-pub struct Memory {
-    data: Vec<i64>,
+pub struct Memory<C = i64> {
+    data: Vec<C>,
+    pages: BTreeMap<usize, Page<C>>,
+    max_address: Option<usize>,
+    image: Option<Arc<[C]>>,
+    // what cells never written read as
+    zero: C,
 }
 
-impl Memory {
-    pub fn new(data: Vec<i64>) -> Self {
-        Self { data }
+impl<C: Cell> Memory<C> {
+    pub fn new(data: Vec<C>) -> Self {
+        Self {
+            data,
+            pages: BTreeMap::new(),
+            max_address: None,
+            image: None,
+            zero: C::default(),
+        }
+    }
+
+    pub fn shared(image: Arc<[C]>) -> Self {
+        Self {
+            data: Vec::new(),
+            pages: BTreeMap::new(),
+            max_address: None,
+            image: Some(image),
+            zero: C::default(),
+        }
+    }
+
+    // moves the dense part into a shared image, keeping the sparse pages and limit
+    pub fn into_shared(self) -> Self {
+        let image = (0..self.len()).map(|index| self[index].clone()).collect();
+        Self {
+            data: Vec::new(),
+            image: Some(image),
+            ..self
+        }
+    }
+
+    pub fn with_max_address(mut self, max_address: usize) -> Self {
+        self.max_address = Some(max_address);
+        self
+    }
+
+    pub fn max_address(&self) -> Option<usize> {
+        self.max_address
+    }
+
+    #[inline]
+    pub fn in_range(&self, address: usize) -> bool {
+        self.max_address.is_none_or(|max| address <= max)
+    }
+
+    // length of the dense part and the image; sparse pages lie beyond it
+    pub fn len(&self) -> usize {
+        self.data.len().max(self.image_len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // the values not yet copied from the shared image
+    pub fn shared_len(&self) -> usize {
+        self.image_len().saturating_sub(self.data.len())
+    }
+
+    fn image_len(&self) -> usize {
+        self.image.as_ref().map_or(0, |image| image.len())
+    }
+
+    pub fn sparse_pages(&self) -> usize {
+        self.pages.len()
+    }
+
+    #[cold]
+    fn sparse(&self, index: usize) -> &C {
+        if let Some(value) = self.image.as_ref().and_then(|image| image.get(index)) {
+            return value;
+        }
+        self.pages
+            .get(&(index / PAGE_SIZE))
+            .map_or(&self.zero, |page| &page[index % PAGE_SIZE])
+    }
+
+    // near writes grow the dense part to a page boundary, or no further than the end
+    // of the image, copying what it covers from the image and any sparse pages; a
+    // sparse page never overlaps the image, or growing over it would lose the image
+    #[cold]
+    fn sparse_mut(&mut self, index: usize) -> &mut C {
+        let old = self.data.len();
+        if index - old >= DENSE_SLACK && index / PAGE_SIZE * PAGE_SIZE >= self.image_len() {
+            let page = self
+                .pages
+                .entry(index / PAGE_SIZE)
+                .or_insert_with(|| vec![C::default(); PAGE_SIZE].into_boxed_slice());
+            return &mut page[index % PAGE_SIZE];
+        }
+        let mut new = (index / PAGE_SIZE + 1) * PAGE_SIZE;
+        if index < self.image_len() {
+            new = new.min(self.image_len());
+        }
+        self.data.resize(new, C::default());
+        if let Some(image) = &self.image {
+            let copied = image.len().min(new);
+            if old < copied {
+                self.data[old..copied].clone_from_slice(&image[old..copied]);
+            }
+            if copied == image.len() {
+                self.image = None;
+            }
+        }
+        for number in old / PAGE_SIZE..new / PAGE_SIZE {
+            if let Some(page) = self.pages.remove(&number) {
+                let base = number * PAGE_SIZE;
+                let skip = old.max(base) - base;
+                for (offset, value) in page.into_vec().into_iter().enumerate().skip(skip) {
+                    self.data[base + offset] = value;
+                }
+            }
+        }
+        &mut self.data[index]
     }
 }
 
+impl<C: fmt::Display> fmt::Display for Memory<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let image = self.image.as_deref().unwrap_or(&[]);
+        let values: Vec<String> = (0..self.data.len().max(image.len()))
+            .map(|index| match self.data.get(index) {
+                Some(value) => value.to_string(),
+                None => image[index].to_string(),
+            })
+            .collect();
+        write!(f, "{}", values.join(","))
+    }
+}
+
+// for the puzzle inputs compiled in, which are known to be valid
 impl From<&str> for Memory {
     fn from(program: &str) -> Memory {
-        Memory::new(
-            program
-                .split(',')
-                .flat_map(|n| n.parse::<i64>().ok())
-                .collect(),
-        )
+        program
+            .parse()
+            .unwrap_or_else(|error| panic!("invalid program: {}", error))
     }
 }
 
-impl Index<usize> for Memory {
-    type Output = i64;
+impl<C: Cell> Index<usize> for Memory<C> {
+    type Output = C;
 
+    #[inline]
     fn index(&self, index: usize) -> &Self::Output {
-        self.data.get(index).unwrap_or(&0)
+        match self.data.get(index) {
+            Some(value) => value,
+            None => self.sparse(index),
+        }
     }
 }
 
-impl IndexMut<usize> for Memory {
+impl<C: Cell> IndexMut<usize> for Memory<C> {
+    #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         if index >= self.data.len() {
-            self.data.resize(index + 1, 0);
+            return self.sparse_mut(index);
         }
         self.data.index_mut(index)
     }
 }
 
-enum StepResult {
-    Continue(usize, usize),
-    Halt,
-}
-
-pub fn run<I: IO>(mut memory: Memory, mut io: I) -> (Memory, I::Value) {
-    let mut pc = 0;
-    let mut rb = 0;
-    let mut counter = 0;
-    'main: loop {
-        counter += 1;
-        match step(pc, rb, &mut memory, &mut io) {
-            StepResult::Continue(at, new_rb) => {
-                pc = at;
-                rb = new_rb;
-            }
-            StepResult::Halt => break 'main,
-        }
-    }
-    (memory, io.output())
-}
-
-fn step<I: IO>(pc: usize, rb: usize, memory: &mut Memory, io: &mut I) -> StepResult {
-    let opcode = memory[pc];
-    let instruction = get_instruction(opcode);
-    let mut params: Parameters = Vec::with_capacity(instruction.num_params);
-    for index in 0..instruction.num_params {
-        let value = memory[pc + 1 + index];
-        let mode = (opcode / (10 * (10i64.pow((index + 1) as u32)))) % 10;
-        match mode {
-            0 => params.push({
-                let position = value as usize;
-                let value = memory[position];
-                Param::Positional(position, value)
-            }),
-            1 => params.push(Param::Immediate(value)),
-            2 => params.push({
-                let position = (value + (rb as i64)) as usize;
-                let value = memory[position];
-                Param::Relative(position, value)
-            }),
-            n => panic!(
-                "invalid param mode {} for param {} in opcode {}",
-                n, index, opcode
-            ),
-        }
-    }
-    let pc = pc + instruction.num_params + 1;
-    match (instruction.handler)(params) {
-        InstructionAction::Store(position, value) => {
-            memory[position] = value;
-            StepResult::Continue(pc, rb)
-        }
-        InstructionAction::Read(position) => match io.read() {
-            Some(value) => {
-                memory[position] = value;
-                StepResult::Continue(pc, rb)
-            }
-            None => StepResult::Halt,
-        },
-        InstructionAction::Write(value) => match io.write(value) {
-            Some(_) => StepResult::Continue(pc, rb),
-            None => StepResult::Halt,
-        },
-        InstructionAction::Jump(to) => StepResult::Continue(to, rb),
-        InstructionAction::Noop => StepResult::Continue(pc, rb),
-        InstructionAction::ChangeRelativeBase(by) => {
-            StepResult::Continue(pc, ((rb as i64) + by) as usize)
-        }
-        InstructionAction::Halt => StepResult::Halt,
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event<C = i64> {
+    NeedsInput,
+    Output(C),
+    Halted,
+}
+
+const DEADLINE_CHECK_INTERVAL: u64 = 1024;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Budget {
+    instructions: Option<u64>,
+    deadline: Option<Instant>,
+}
+
+impl Budget {
+    pub fn instructions(count: u64) -> Self {
+        Self {
+            instructions: Some(count),
+            deadline: None,
+        }
+    }
+
+    pub fn deadline(deadline: Instant) -> Self {
+        Self {
+            instructions: None,
+            deadline: Some(deadline),
+        }
+    }
+
+    pub fn timeout(duration: Duration) -> Self {
+        Self::deadline(Instant::now() + duration)
+    }
+
+    pub fn remaining(&self) -> Option<u64> {
+        self.instructions
+    }
+}
+
+pub enum Outcome<I: IO> {
+    Finished(Memory, I::Value),
+    OutOfFuel(Machine, I),
+}
+
+#[derive(Clone, Debug)]
+pub struct Machine<C = i64> {
+    memory: Memory<C>,
+    pc: usize,
+    rb: i64,
+    input: VecDeque<C>,
+    executed: u64,
+    code: Option<Code<C>>,
+    arithmetic: Arithmetic,
+    isa: Arc<InstructionSet<C>>,
+}
+
+impl<C: Cell> Machine<C> {
+    pub fn new(memory: Memory<C>) -> Self {
+        Self::with_engine(memory, Engine::Interpreter)
+    }
+
+    pub fn with_engine(memory: Memory<C>, engine: Engine) -> Self {
+        let isa = InstructionSet::standard();
+        let code = match engine {
+            Engine::Interpreter => None,
+            Engine::Threaded => Some(Code::new(&isa, &memory)),
+        };
+        Self {
+            memory,
+            pc: 0,
+            rb: 0,
+            input: VecDeque::new(),
+            executed: 0,
+            code,
+            arithmetic: Arithmetic::default(),
+            isa,
+        }
+    }
+
+    pub fn with_registers(mut self, pc: usize, rb: i64) -> Self {
+        self.pc = pc;
+        self.rb = rb;
+        self
+    }
+
+    pub fn with_instruction_set(mut self, isa: Arc<InstructionSet<C>>) -> Self {
+        if let Some(code) = self.code.as_mut() {
+            *code = Code::new(&isa, &self.memory);
+        }
+        self.isa = isa;
+        self
+    }
+
+    pub fn instruction_set(&self) -> &Arc<InstructionSet<C>> {
+        &self.isa
+    }
+
+    pub fn with_arithmetic(mut self, arithmetic: Arithmetic) -> Self {
+        self.arithmetic = arithmetic;
+        self
+    }
+
+    pub fn arithmetic(&self) -> Arithmetic {
+        self.arithmetic
+    }
+
+    pub fn engine(&self) -> Engine {
+        match self.code {
+            Some(_) => Engine::Threaded,
+            None => Engine::Interpreter,
+        }
+    }
+
+    pub fn executed(&self) -> u64 {
+        self.executed
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn rb(&self) -> i64 {
+        self.rb
+    }
+
+    pub fn memory(&self) -> &Memory<C> {
+        &self.memory
+    }
+
+    pub fn into_memory(self) -> Memory<C> {
+        self.memory
+    }
+
+    pub fn input(&mut self, value: C) {
+        self.input.push_back(value);
+    }
+
+    // the instruction set is saved as its level; handlers of custom opcodes are code
+    // and cannot be saved, so machines using them refuse
+    pub fn snapshot<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        if !self.isa.is_standard() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot snapshot a machine with a custom instruction set",
+            ));
+        }
+        let input: Vec<String> = self.input.iter().map(C::to_string).collect();
+        writeln!(writer, "pc {}", self.pc)?;
+        writeln!(writer, "rb {}", self.rb)?;
+        writeln!(writer, "executed {}", self.executed)?;
+        writeln!(writer, "input {}", input.join(","))?;
+        if self.arithmetic == Arithmetic::Wrapping {
+            writeln!(writer, "arithmetic wrapping")?;
+        }
+        if self.isa.level() != Level::Day9 {
+            writeln!(writer, "level {}", self.isa.level())?;
+        }
+        if self.engine() == Engine::Threaded {
+            writeln!(writer, "engine threaded")?;
+        }
+        if let Some(max_address) = self.memory.max_address {
+            writeln!(writer, "max_address {}", max_address)?;
+        }
+        writeln!(writer, "memory {}", self.memory)?;
+        for (number, page) in &self.memory.pages {
+            let values: Vec<String> = page.iter().map(C::to_string).collect();
+            writeln!(writer, "page {} {}", number * PAGE_SIZE, values.join(","))?;
+        }
+        Ok(())
+    }
+
+    pub fn restore<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut machine = Machine::new(Memory::new(Vec::new()));
+        let mut max_address = None;
+        let mut pages = Vec::new();
+        let mut engine = Engine::Interpreter;
+        for line in reader.lines() {
+            let line = line?;
+            let (key, value) = line.split_once(' ').unwrap_or((&line, ""));
+            match key {
+                "pc" => machine.pc = parse(value)?,
+                "rb" => machine.rb = parse(value)?,
+                "executed" => machine.executed = parse(value)?,
+                "input" => {
+                    machine.input = value
+                        .split(',')
+                        .filter(|value| !value.is_empty())
+                        .map(parse)
+                        .collect::<io::Result<VecDeque<C>>>()?
+                }
+                "memory" => machine.memory = value.parse()?,
+                "arithmetic" => machine.arithmetic = parse(value)?,
+                "level" => machine.isa = Arc::new(InstructionSet::new(parse(value)?)),
+                "engine" => engine = parse(value)?,
+                "max_address" => max_address = Some(parse(value)?),
+                "page" => {
+                    let (base, values) = value.split_once(' ').unwrap_or((value, ""));
+                    pages.push((parse::<usize>(base)?, values.parse::<Memory<C>>()?));
+                }
+                "" => continue,
+                key => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("unknown snapshot field '{}'", key),
+                    ))
+                }
+            }
+        }
+        machine.memory.max_address = max_address;
+        if engine == Engine::Threaded {
+            machine.code = Some(Code::new(&machine.isa, &machine.memory));
+        }
+        for (base, page) in pages {
+            for (offset, value) in page.data.into_iter().enumerate() {
+                if value != C::default() {
+                    machine.memory[base + offset] = value;
+                }
+            }
+        }
+        Ok(machine)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.snapshot(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Machine::restore(BufReader::new(File::open(path)?))
+    }
+
+    pub fn resume(&mut self) -> Result<Event<C>, VmError> {
+        self.resume_traced(&mut NullTrace)
+    }
+
+    pub fn resume_traced<T: Trace<C>>(&mut self, trace: &mut T) -> Result<Event<C>, VmError> {
+        loop {
+            if let Some(event) = self.step_traced(trace)? {
+                return Ok(event);
+            }
+        }
+    }
+
+    // None once the budget is used up, with the machine left ready to resume
+    pub fn resume_within(&mut self, budget: &mut Budget) -> Result<Option<Event<C>>, VmError> {
+        loop {
+            if budget.instructions == Some(0) {
+                return Ok(None);
+            }
+            if let Some(deadline) = budget.deadline {
+                if self.executed.is_multiple_of(DEADLINE_CHECK_INTERVAL)
+                    && Instant::now() >= deadline
+                {
+                    return Ok(None);
+                }
+            }
+            let executed = self.executed;
+            let event = self.step()?;
+            if let Some(instructions) = budget.instructions.as_mut() {
+                *instructions -= self.executed - executed;
+            }
+            if event.is_some() {
+                return Ok(event);
+            }
+        }
+    }
+
+    pub fn step(&mut self) -> Result<Option<Event<C>>, VmError> {
+        self.step_traced(&mut NullTrace)
+    }
+
+    fn store(&mut self, position: usize, value: C) {
+        self.memory[position] = value;
+        if let Some(code) = self.code.as_mut() {
+            code.invalidate(position);
+        }
+    }
+
+    pub fn step_traced<T: Trace<C>>(&mut self, trace: &mut T) -> Result<Option<Event<C>>, VmError> {
+        let pc = self.pc;
+        // each engine gets its own copy of execute, so that decoding inlines into one
+        // and the cache lookup into the other
+        match self.code.as_mut() {
+            Some(code) => {
+                let op = code.fetch(&self.isa, &self.memory, pc)?;
+                self.execute(pc, op, trace)
+            }
+            None => {
+                let op = Op::decode(&self.isa, &self.memory, pc)?;
+                self.execute(pc, op, trace)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn execute<T: Trace<C>>(
+        &mut self,
+        pc: usize,
+        op: Op<C>,
+        trace: &mut T,
+    ) -> Result<Option<Event<C>>, VmError> {
+        let Op {
+            opcode,
+            instruction,
+            modes,
+            values,
+        } = op;
+        let mut params: Parameters<C> = std::array::from_fn(|_| Param::Immediate(C::default()));
+        let operands = params.iter_mut().zip(values).zip(modes);
+        for (index, ((param, value), mode)) in operands.take(instruction.num_params).enumerate() {
+            let negative = |address| VmError::NegativeAddress {
+                pc,
+                opcode,
+                param: index,
+                address,
+            };
+            *param = match mode {
+                1 => Param::Immediate(value),
+                0 | 2 => {
+                    let value = value.to_i64().ok_or(VmError::TooWide {
+                        pc,
+                        param: Some(index),
+                    })?;
+                    let address = match mode {
+                        0 => value,
+                        _ => self
+                            .arithmetic
+                            .add(value, self.rb)
+                            .ok_or(VmError::Overflow { pc, opcode })?,
+                    };
+                    if address < 0 {
+                        return Err(negative(address));
+                    }
+                    let position = address as usize;
+                    if !self.memory.in_range(position) {
+                        return Err(VmError::AddressOutOfRange {
+                            pc,
+                            opcode,
+                            param: index,
+                            address: position,
+                        });
+                    }
+                    let value = self.memory[position].clone();
+                    if mode == 0 {
+                        Param::Positional(position, value)
+                    } else {
+                        Param::Relative(position, value)
+                    }
+                }
+                _ => {
+                    return Err(VmError::InvalidMode {
+                        pc,
+                        opcode,
+                        param: index,
+                        mode,
+                    })
+                }
+            };
+        }
+        let params = &params[..instruction.num_params];
+        let next = pc + instruction.num_params + 1;
+        let action =
+            (instruction.handler)(params, self.arithmetic).map_err(|fault| fault.at(pc, opcode))?;
+        let (effect, event) = match action {
+            InstructionAction::Store(position, value) => {
+                self.store(position, value.clone());
+                self.pc = next;
+                (Effect::Store(position, value), None)
+            }
+            InstructionAction::Read(position) => match self.input.pop_front() {
+                Some(value) => {
+                    self.store(position, value.clone());
+                    self.pc = next;
+                    (Effect::Input(position, value), None)
+                }
+                None => return Ok(Some(Event::NeedsInput)),
+            },
+            InstructionAction::Write(value) => {
+                self.pc = next;
+                (Effect::Output(value.clone()), Some(Event::Output(value)))
+            }
+            InstructionAction::Jump(to) => {
+                self.pc = to;
+                (Effect::Jump(to), None)
+            }
+            InstructionAction::Noop => {
+                self.pc = next;
+                (Effect::None, None)
+            }
+            InstructionAction::ChangeRelativeBase(by) => {
+                self.rb = self
+                    .arithmetic
+                    .add(self.rb, by)
+                    .ok_or(VmError::Overflow { pc, opcode })?;
+                self.pc = next;
+                (Effect::RelativeBase(self.rb), None)
+            }
+            InstructionAction::Halt => (Effect::Halt, Some(Event::Halted)),
+        };
+        self.executed += 1;
+        trace.trace(&TraceEvent {
+            pc,
+            opcode,
+            instruction: &instruction,
+            params,
+            effect,
+        });
+        Ok(event)
+    }
+}
+
+impl Machine {
+    // resumes with Op::decode_allocating, for bench
+    pub fn resume_allocating(&mut self) -> Result<Event, VmError> {
+        loop {
+            let pc = self.pc;
+            let op = Op::decode_allocating(&self.memory, pc)?;
+            if let Some(event) = self.execute(pc, op, &mut NullTrace)? {
+                return Ok(event);
+            }
+        }
+    }
+}
+
+fn parse<T: FromStr>(value: &str) -> io::Result<T> {
+    value.parse().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid snapshot value '{}'", value),
+        )
+    })
+}
+
+// hands a machine event to the IO, false once the run is over
+fn exchange<I: IO>(machine: &mut Machine, io: &mut I, event: Event) -> bool {
+    match event {
+        Event::NeedsInput => io.read().map(|value| machine.input(value)).is_some(),
+        Event::Output(value) => io.write(value).is_some(),
+        Event::Halted => false,
+    }
+}
+
+pub fn run<I: IO>(memory: Memory, io: I) -> Result<(Memory, I::Value), VmError> {
+    run_traced(memory, io, NullTrace)
+}
+
+pub fn run_with_engine<I: IO>(
+    memory: Memory,
+    mut io: I,
+    engine: Engine,
+) -> Result<(Memory, I::Value), VmError> {
+    let mut machine = Machine::with_engine(memory, engine);
+    loop {
+        let event = machine.resume()?;
+        if !exchange(&mut machine, &mut io, event) {
+            break;
+        }
+    }
+    Ok((machine.into_memory(), io.output()))
+}
+
+pub fn run_traced<I: IO, T: Trace>(
+    memory: Memory,
+    mut io: I,
+    mut trace: T,
+) -> Result<(Memory, I::Value), VmError> {
+    let mut machine = Machine::new(memory);
+    loop {
+        let event = machine.resume_traced(&mut trace)?;
+        if !exchange(&mut machine, &mut io, event) {
+            break;
+        }
+    }
+    Ok((machine.into_memory(), io.output()))
+}
+
+pub fn run_with_budget<I: IO>(
+    mut machine: Machine,
+    mut io: I,
+    mut budget: Budget,
+) -> Result<Outcome<I>, VmError> {
+    loop {
+        match machine.resume_within(&mut budget)? {
+            Some(event) => {
+                if !exchange(&mut machine, &mut io, event) {
+                    break;
+                }
+            }
+            None => return Ok(Outcome::OutOfFuel(machine, io)),
+        }
+    }
+    Ok(Outcome::Finished(machine.into_memory(), io.output()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::Program;
+    use std::collections::HashMap;
+
+    // a fixed xorshift sequence, so failures reproduce
+    struct Numbers(u64);
+
+    impl Numbers {
+        fn next(&mut self, below: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % below as u64) as usize
+        }
+    }
+
+    // writes around the image end and the sparse threshold behind it, checking every
+    // address written so far and the whole image against a plain map after each one
+    fn check_writes(mut memory: Memory, seed: u64) {
+        let image_len = memory.len();
+        let mut model: HashMap<usize, i64> = (0..image_len).map(|i| (i, memory[i])).collect();
+        let mut numbers = Numbers(seed);
+        let centres = [
+            0,
+            image_len / 2,
+            image_len,
+            image_len + DENSE_SLACK,
+            3 * DENSE_SLACK,
+            1 << 40,
+        ];
+        for step in 0..400 {
+            let centre = centres[numbers.next(centres.len())];
+            let address = (centre + numbers.next(4 * PAGE_SIZE)).saturating_sub(2 * PAGE_SIZE);
+            let value = step as i64 + 1;
+            memory[address] = value;
+            model.insert(address, value);
+            for (&address, &value) in &model {
+                assert_eq!(
+                    memory[address], value,
+                    "address {} after step {}",
+                    address, step
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sparse_memory_matches_a_map() {
+        for (len, seed) in [(0, 5), (100, 6), (5000, 7)] {
+            check_writes(Memory::new(vec![3; len]), seed);
+        }
+        let mut memory = Memory::new(vec![1, 2, 3]);
+        memory[1 << 40] = 7;
+        assert_eq!(memory.len(), 3);
+        assert_eq!(memory.sparse_pages(), 1);
+    }
+
+    #[test]
+    fn address_limit_stops_far_accesses() {
+        let memory = Memory::from("1101,1,1,100,99").with_max_address(99);
+        let error = Machine::new(memory).resume().unwrap_err();
+        assert_eq!(
+            error,
+            VmError::AddressOutOfRange {
+                pc: 0,
+                opcode: 1101,
+                param: 2,
+                address: 100,
+            }
+        );
+    }
+
+    #[test]
+    fn shared_image_survives_sparse_writes() {
+        for (len, seed) in [(67172, 1), (1000, 2), (2048, 3), (70001, 4)] {
+            let program = Program::from(Memory::new((0..len as i64).map(|i| i * 7).collect()));
+            check_writes(program.memory(), seed);
+        }
+    }
+
+    #[test]
+    fn dense_growth_keeps_image_under_sparse_page() {
+        let program = Program::from(Memory::new((0..67172).collect()));
+        let mut memory = program.memory();
+        memory[67319] = -1;
+        memory[5000] = -2;
+        memory[67200] = -3;
+        assert_eq!(memory[66876], 66876);
+        assert_eq!(memory[67319], -1);
+    }
+
+    #[test]
+    fn snapshot_keeps_level_and_engine() {
+        let isa = Arc::new(InstructionSet::new(Level::Day5));
+        let machine = Machine::with_engine(Memory::from("3,0,4,0,99"), Engine::Threaded)
+            .with_instruction_set(isa);
+        let mut snapshot = Vec::new();
+        machine.snapshot(&mut snapshot).unwrap();
+        let restored: Machine = Machine::restore(snapshot.as_slice()).unwrap();
+        assert_eq!(restored.instruction_set().level(), Level::Day5);
+        assert_eq!(restored.engine(), Engine::Threaded);
+    }
+
+    #[test]
+    fn snapshot_refuses_custom_instruction_set() {
+        let mut isa = InstructionSet::default();
+        isa.remove(9);
+        let machine = Machine::new(Memory::from("99")).with_instruction_set(Arc::new(isa));
+        assert!(machine.snapshot(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn program_keeps_sparse_pages_and_limit() {
+        let mut memory = Memory::new(vec![1, 2, 3]).with_max_address(1 << 20);
+        memory[500_000] = 42;
+        let program = Program::from(memory);
+        let spawned = program.memory();
+        assert_eq!(spawned[500_000], 42);
+        assert_eq!(spawned[2], 3);
+        assert_eq!(spawned.max_address(), Some(1 << 20));
     }
 }
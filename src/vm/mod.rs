@@ -1,7 +1,47 @@
+mod ascii;
+mod asm;
+mod async_io;
+mod batch;
+mod bigint;
+mod cells;
+mod cfg;
+mod combinators;
+mod debugger;
+mod disasm;
+mod error;
 mod instructions;
 mod io;
+mod load;
 mod machine;
+mod network;
+mod pipeline;
+mod profile;
+mod program;
+mod session;
+mod threaded;
+mod trace;
+mod transpile;
 
+pub use ascii::*;
+pub use asm::*;
+pub use async_io::*;
+pub use batch::*;
+pub use bigint::*;
+pub use cells::*;
+pub use cfg::*;
+pub use combinators::*;
+pub use debugger::*;
+pub use disasm::*;
+pub use error::*;
 pub use instructions::*;
 pub use io::*;
+pub use load::*;
 pub use machine::*;
+pub use network::*;
+pub use pipeline::*;
+pub use profile::*;
+pub use program::*;
+pub use session::*;
+pub use threaded::*;
+pub use trace::*;
+pub use transpile::*;
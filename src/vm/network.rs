@@ -0,0 +1,237 @@
+use crate::vm::error::VmError;
+use crate::vm::machine::{Budget, Event, Machine, Memory};
+use std::collections::VecDeque;
+
+const QUANTUM: u64 = 10_000;
+
+pub type NodeId = usize;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    Halted,
+    Idle,
+}
+
+struct Node {
+    machine: Machine,
+    inbox: VecDeque<i64>,
+    outbox: Vec<i64>,
+    links: Vec<NodeId>,
+    idle_input: Option<i64>,
+    last_output: Option<i64>,
+    waiting: bool,
+    halted: bool,
+}
+
+// machines stepped round-robin on the calling thread; outputs of a node go to the
+// inboxes of its links, or to its outbox when it has none
+pub struct Network {
+    nodes: Vec<Node>,
+    quantum: u64,
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Network {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            quantum: QUANTUM,
+        }
+    }
+
+    pub fn quantum(mut self, instructions: u64) -> Self {
+        self.quantum = instructions.max(1);
+        self
+    }
+
+    pub fn add(&mut self, memory: Memory) -> NodeId {
+        self.add_machine(Machine::new(memory))
+    }
+
+    pub fn add_machine(&mut self, machine: Machine) -> NodeId {
+        self.nodes.push(Node {
+            machine,
+            inbox: VecDeque::new(),
+            outbox: Vec::new(),
+            links: Vec::new(),
+            idle_input: None,
+            last_output: None,
+            waiting: false,
+            halted: false,
+        });
+        self.nodes.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn link(&mut self, from: NodeId, to: NodeId) {
+        self.nodes[from].links.push(to);
+    }
+
+    // read by the node instead of blocking when its inbox is empty
+    pub fn idle_input(&mut self, node: NodeId, value: i64) {
+        self.nodes[node].idle_input = Some(value);
+    }
+
+    pub fn send(&mut self, node: NodeId, value: i64) {
+        self.nodes[node].inbox.push_back(value);
+    }
+
+    pub fn take_outputs(&mut self, node: NodeId) -> Vec<i64> {
+        self.nodes[node].outbox.split_off(0)
+    }
+
+    pub fn last_output(&self, node: NodeId) -> Option<i64> {
+        self.nodes[node].last_output
+    }
+
+    pub fn machine(&self, node: NodeId) -> &Machine {
+        &self.nodes[node].machine
+    }
+
+    pub fn is_halted(&self, node: NodeId) -> bool {
+        self.nodes[node].halted
+    }
+
+    // gives every live node one turn, which ends when it halts, finds its inbox empty
+    // or uses up the quantum; false once every live node has found its inbox empty
+    // twice without any input or output in between
+    pub fn round(&mut self) -> Result<bool, VmError> {
+        let mut progress = false;
+        for index in 0..self.nodes.len() {
+            progress |= self.turn(index)?;
+        }
+        Ok(progress)
+    }
+
+    pub fn run(&mut self) -> Result<Status, VmError> {
+        loop {
+            if self.nodes.iter().all(|node| node.halted) {
+                return Ok(Status::Halted);
+            }
+            if !self.round()? {
+                return Ok(Status::Idle);
+            }
+        }
+    }
+
+    fn turn(&mut self, index: NodeId) -> Result<bool, VmError> {
+        let mut budget = Budget::instructions(self.quantum);
+        let mut progress = false;
+        while !self.nodes[index].halted {
+            let node = &mut self.nodes[index];
+            match node.machine.resume_within(&mut budget)? {
+                None => return Ok(true),
+                Some(Event::Halted) => {
+                    node.halted = true;
+                    progress = true;
+                }
+                Some(Event::NeedsInput) => match node.inbox.pop_front() {
+                    Some(value) => {
+                        node.machine.input(value);
+                        node.waiting = false;
+                        progress = true;
+                    }
+                    None => {
+                        if let Some(value) = node.idle_input {
+                            node.machine.input(value);
+                        }
+                        progress |= !node.waiting;
+                        node.waiting = true;
+                        break;
+                    }
+                },
+                Some(Event::Output(value)) => {
+                    node.last_output = Some(value);
+                    node.waiting = false;
+                    progress = true;
+                    if node.links.is_empty() {
+                        node.outbox.push(value);
+                    }
+                    for link in 0..self.nodes[index].links.len() {
+                        let to = self.nodes[index].links[link];
+                        self.nodes[to].inbox.push_back(value);
+                    }
+                }
+            }
+        }
+        Ok(progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // reads a value and writes it back, forever
+    const ECHO: &str = "3,9,4,9,1105,1,0,0,0,0";
+    // reads a value and writes it plus one, halting once that reaches 10
+    const PING: &str = "3,20,101,1,20,20,4,20,1007,20,10,21,1005,21,0,99,0,0,0,0,0,0";
+
+    fn sink(sources: &[&str], quantum: u64) -> Vec<i64> {
+        let mut network = Network::new().quantum(quantum);
+        let sink = network.add(Memory::from(ECHO));
+        for source in sources {
+            let node = network.add(Memory::from(*source));
+            network.link(node, sink);
+        }
+        assert_eq!(network.run(), Ok(Status::Idle));
+        network.take_outputs(sink)
+    }
+
+    #[test]
+    fn two_machines_play_ping_pong() {
+        let mut network = Network::new();
+        let ping = network.add(Memory::from(PING));
+        let pong = network.add(Memory::from(PING));
+        network.link(ping, pong);
+        network.link(pong, ping);
+        network.send(ping, 0);
+        assert_eq!(network.run(), Ok(Status::Halted));
+        assert!(network.is_halted(ping) && network.is_halted(pong));
+        assert_eq!(network.last_output(ping), Some(11));
+        assert_eq!(network.last_output(pong), Some(10));
+        assert_eq!(network.take_outputs(ping), vec![]);
+    }
+
+    #[test]
+    fn blocked_machines_are_idle() {
+        let mut network = Network::new();
+        let first = network.add(Memory::from(ECHO));
+        let second = network.add(Memory::from(ECHO));
+        assert_eq!(network.run(), Ok(Status::Idle));
+        network.send(second, 5);
+        network.send(first, 7);
+        assert_eq!(network.run(), Ok(Status::Idle));
+        assert_eq!(network.take_outputs(first), vec![7]);
+        assert_eq!(network.take_outputs(second), vec![5]);
+        assert!(!network.is_halted(first));
+    }
+
+    #[test]
+    fn empty_network_is_halted() {
+        let mut network = Network::new();
+        assert!(network.is_empty());
+        assert_eq!(network.run(), Ok(Status::Halted));
+    }
+
+    #[test]
+    fn outputs_arrive_in_round_robin_order() {
+        let sources = ["104,1,104,2,99", "104,3,104,4,99"];
+        for _ in 0..2 {
+            assert_eq!(sink(&sources, QUANTUM), vec![1, 2, 3, 4]);
+            assert_eq!(sink(&sources, 1), vec![1, 3, 2, 4]);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::vm::error::VmError;
+use crate::vm::network::{Network, NodeId};
+use crate::vm::program::Program;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Topology {
+    // the last node's output is the result
+    Linear,
+    // the last node feeds the first, until they all halt
+    Ring,
+}
+
+// copies of one program, each reading what the one before it writes, like the
+// amplifiers of day7
+pub struct Pipeline {
+    program: Program,
+    initial: Vec<Vec<i64>>,
+    topology: Topology,
+    injection: Option<i64>,
+}
+
+impl Pipeline {
+    pub fn new(program: &Program, copies: usize) -> Self {
+        Self {
+            program: program.clone(),
+            initial: vec![Vec::new(); copies],
+            topology: Topology::Linear,
+            injection: None,
+        }
+    }
+
+    pub fn topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    // values a node reads before anything from the node before it
+    pub fn initial(mut self, node: NodeId, values: &[i64]) -> Self {
+        assert!(
+            node < self.initial.len(),
+            "node {} is not in a pipeline of {} copies",
+            node,
+            self.initial.len()
+        );
+        self.initial[node].extend_from_slice(values);
+        self
+    }
+
+    // one initial value per node, in order
+    pub fn phases(mut self, phases: &[i64]) -> Self {
+        assert_eq!(
+            phases.len(),
+            self.initial.len(),
+            "a pipeline of {} copies needs as many phases",
+            self.initial.len()
+        );
+        for (initial, &phase) in self.initial.iter_mut().zip(phases) {
+            initial.push(phase);
+        }
+        self
+    }
+
+    // sent to the first node after its initial values, to start the pipeline
+    pub fn inject(mut self, value: i64) -> Self {
+        self.injection = Some(value);
+        self
+    }
+
+    // the last output of the last node, once every node has halted or waits for
+    // input that will not come
+    pub fn run(&self) -> Result<Option<i64>, VmError> {
+        let mut network = Network::new();
+        let nodes: Vec<NodeId> = self
+            .initial
+            .iter()
+            .map(|values| {
+                let node = network.add(self.program.memory());
+                values.iter().for_each(|&value| network.send(node, value));
+                node
+            })
+            .collect();
+        let (first, last) = match (nodes.first(), nodes.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return Ok(None),
+        };
+        for pair in nodes.windows(2) {
+            network.link(pair[0], pair[1]);
+        }
+        if self.topology == Topology::Ring {
+            network.link(last, first);
+        }
+        if let Some(value) = self.injection {
+            network.send(first, value);
+        }
+        network.run()?;
+        Ok(network.last_output(last))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the first amplifier example of day7
+    const AMPLIFIER: &str = "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0";
+
+    #[test]
+    fn phases_and_initial_values_reach_their_node() {
+        let program = Program::from(AMPLIFIER);
+        let phases = Pipeline::new(&program, 5)
+            .phases(&[4, 3, 2, 1, 0])
+            .inject(0);
+        assert_eq!(phases.run(), Ok(Some(43210)));
+        let initial = (0..5).fold(Pipeline::new(&program, 5), |pipeline, node| {
+            pipeline.initial(node, &[4 - node as i64])
+        });
+        assert_eq!(initial.inject(0).run(), Ok(Some(43210)));
+    }
+
+    #[test]
+    #[should_panic(expected = "node 5 is not in a pipeline of 5 copies")]
+    fn initial_rejects_a_missing_node() {
+        Pipeline::new(&Program::from(AMPLIFIER), 5).initial(5, &[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a pipeline of 5 copies needs as many phases")]
+    fn phases_rejects_a_wrong_count() {
+        Pipeline::new(&Program::from(AMPLIFIER), 5).phases(&[0, 1, 2]);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::vm::disasm::{label, Disassembly, Item};
+use crate::vm::instructions::Param;
+use crate::vm::trace::{Effect, Trace, TraceEvent};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::ops::RangeBounds;
+
+const TOP: usize = 20;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Loop {
+    pub start: usize,
+    pub end: usize,
+    pub iterations: u64,
+    pub instructions: u64,
+}
+
+// counts executed instructions per pc and per opcode; a taken direct jump back to or
+// before its own pc closes a loop over start..=end, while indirect ones are returns
+// from calls
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    hits: BTreeMap<usize, u64>,
+    opcodes: HashMap<i64, (&'static str, u64)>,
+    backward: HashMap<(usize, usize), u64>,
+    total: u64,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn hits(&self, pc: usize) -> u64 {
+        self.hits.get(&pc).copied().unwrap_or(0)
+    }
+
+    // (mnemonic, count), most executed first
+    pub fn opcodes(&self) -> Vec<(&'static str, u64)> {
+        let mut opcodes: Vec<(&'static str, u64)> = self.opcodes.values().copied().collect();
+        opcodes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        opcodes
+    }
+
+    // pcs by hit count, most executed first
+    pub fn hottest(&self) -> Vec<(usize, u64)> {
+        let mut pcs: Vec<(usize, u64)> = self.hits.iter().map(|(&pc, &hits)| (pc, hits)).collect();
+        pcs.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        pcs
+    }
+
+    // loops by instructions executed inside them, hottest first
+    pub fn loops(&self) -> Vec<Loop> {
+        let mut loops: Vec<Loop> = self
+            .backward
+            .iter()
+            .map(|(&(start, end), &iterations)| Loop {
+                start,
+                end,
+                iterations,
+                instructions: self.hits_in(start..=end),
+            })
+            .collect();
+        loops.sort_by(|a, b| {
+            b.instructions
+                .cmp(&a.instructions)
+                .then(a.start.cmp(&b.start))
+                .then(a.end.cmp(&b.end))
+        });
+        loops
+    }
+
+    fn hits_in(&self, pcs: impl RangeBounds<usize>) -> u64 {
+        self.hits.range(pcs).map(|(_, &hits)| hits).sum()
+    }
+
+    fn share(&self, count: u64) -> f64 {
+        100.0 * count as f64 / self.total.max(1) as f64
+    }
+
+    // the disassembly listing with a hit count column after the address
+    pub fn annotate(&self, disassembly: &Disassembly) -> String {
+        let mut listing = String::new();
+        for item in &disassembly.items {
+            let address = item.address();
+            let hits = match item {
+                Item::Code(_) => self.hits(address),
+                Item::Data(_, values) => self.hits_in(address..address + values.len()),
+            };
+            let label = if disassembly.labels.contains(&address) {
+                format!("{}:", label(address))
+            } else {
+                String::new()
+            };
+            let hits = if hits > 0 {
+                hits.to_string()
+            } else {
+                String::new()
+            };
+            listing.push_str(&format!(
+                "{:>5}  {:>12}  {:<8}{}\n",
+                address,
+                hits,
+                label,
+                disassembly.render(item)
+            ));
+        }
+        listing
+    }
+}
+
+impl<C> Trace<C> for Profile {
+    fn trace(&mut self, event: &TraceEvent<C>) {
+        *self.hits.entry(event.pc).or_insert(0) += 1;
+        self.total += 1;
+        self.opcodes
+            .entry(event.opcode % 100)
+            .or_insert((event.instruction.mnemonic, 0))
+            .1 += 1;
+        if let (&Effect::Jump(to), Some(Param::Immediate(_))) = (&event.effect, event.params.last())
+        {
+            if to <= event.pc {
+                *self.backward.entry((to, event.pc)).or_insert(0) += 1;
+            }
+        }
+    }
+}
+
+// loop and pc lines start with an address, like the disassembly listing
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "instructions {}", self.total)?;
+        writeln!(f)?;
+        writeln!(f, "opcodes")?;
+        for (mnemonic, count) in self.opcodes() {
+            writeln!(
+                f,
+                "  {:<8}{:>12}  {:>6.2}%",
+                mnemonic,
+                count,
+                self.share(count)
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "loops")?;
+        for hot in self.loops().iter().take(TOP) {
+            writeln!(
+                f,
+                "{:>5}  ..{:<5}  {:>12} iterations {:>12} instructions  {:>6.2}%",
+                hot.start,
+                hot.end,
+                hot.iterations,
+                hot.instructions,
+                self.share(hot.instructions)
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "hottest")?;
+        for (pc, hits) in self.hottest().into_iter().take(TOP) {
+            writeln!(f, "{:>5}  {:>12}  {:>6.2}%", pc, hits, self.share(hits))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{run_traced, InputOutput, Memory, NullIO};
+
+    fn profile(program: &str) -> Profile {
+        let mut profile = Profile::new();
+        let io = InputOutput::new(NullIO::new(), NullIO::new());
+        run_traced(Memory::from(program), io, &mut profile).unwrap();
+        profile
+    }
+
+    #[test]
+    fn counts_a_countdown_loop() {
+        // sets a counter to 3, then decrements it until it is 0
+        let profile = profile("1101,3,0,20,1001,20,-1,20,1005,20,4,99");
+        assert_eq!(profile.total(), 8);
+        assert_eq!(profile.hottest(), vec![(4, 3), (8, 3), (0, 1), (11, 1)]);
+        assert_eq!(profile.hits(5), 0);
+        assert_eq!(
+            profile.loops(),
+            vec![Loop {
+                start: 4,
+                end: 8,
+                iterations: 2,
+                instructions: 6,
+            }]
+        );
+        assert_eq!(profile.opcodes(), vec![("ADD", 4), ("JNZ", 3), ("HALT", 1)]);
+    }
+
+    #[test]
+    fn far_code_gets_its_own_count() {
+        let profile = profile("1101,99,0,1000000000000,1105,1,1000000000000");
+        assert_eq!(profile.hits(1_000_000_000_000), 1);
+        assert_eq!(profile.total(), 3);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::vm::load::LoadError;
+use crate::vm::machine::{Machine, Memory};
+use std::io::{self, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+// a parsed program image, shared by every memory spawned from it until they write
+#[derive(Clone, Debug)]
+pub struct Program {
+    memory: Memory,
+}
+
+impl Program {
+    pub fn len(&self) -> usize {
+        self.memory.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memory.is_empty()
+    }
+
+    pub fn memory(&self) -> Memory {
+        self.memory.clone()
+    }
+
+    pub fn spawn(&self) -> Machine {
+        Machine::new(self.memory())
+    }
+
+    pub fn read<R: Read>(reader: R) -> io::Result<Program> {
+        Memory::read(reader).map(Program::from)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Program> {
+        Memory::load(path).map(Program::from)
+    }
+}
+
+// spawned memories get the sparse pages and the address limit too
+impl From<Memory> for Program {
+    fn from(memory: Memory) -> Program {
+        Program {
+            memory: memory.into_shared(),
+        }
+    }
+}
+
+impl FromStr for Program {
+    type Err = LoadError;
+
+    fn from_str(program: &str) -> Result<Program, LoadError> {
+        program.parse::<Memory>().map(Program::from)
+    }
+}
+
+impl From<&str> for Program {
+    fn from(program: &str) -> Program {
+        Program::from(Memory::from(program))
+    }
+}
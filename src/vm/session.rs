@@ -0,0 +1,346 @@
+use crate::vm::error::VmError;
+use crate::vm::io::IO;
+use crate::vm::machine::{Event, Machine, Memory};
+use crate::vm::trace::{Effect, Trace, TraceEvent};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+
+// what a program read or wrote, with the index of the instruction that did it, and
+// how the run ended: the program halted, the input ran out at a read, or the output
+// refused the value of the last write
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Record {
+    Input(u64, i64),
+    Output(u64, i64),
+    Halt(u64),
+    InputEnd(u64),
+    OutputClosed(u64),
+}
+
+impl Record {
+    pub fn at(&self) -> u64 {
+        match self {
+            Record::Input(at, _)
+            | Record::Output(at, _)
+            | Record::Halt(at)
+            | Record::InputEnd(at)
+            | Record::OutputClosed(at) => *at,
+        }
+    }
+
+    fn parse(line: &str) -> io::Result<Record> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let number = |index: usize| -> io::Result<i64> {
+            words
+                .get(index)
+                .and_then(|word| word.parse().ok())
+                .ok_or_else(|| invalid(line))
+        };
+        let at = number(1)? as u64;
+        match (words[0], words.len()) {
+            ("in", 3) => Ok(Record::Input(at, number(2)?)),
+            ("out", 3) => Ok(Record::Output(at, number(2)?)),
+            ("halt", 2) => Ok(Record::Halt(at)),
+            ("eof", 2) => Ok(Record::InputEnd(at)),
+            ("closed", 2) => Ok(Record::OutputClosed(at)),
+            _ => Err(invalid(line)),
+        }
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Record::Input(at, value) => write!(f, "in {} {}", at, value),
+            Record::Output(at, value) => write!(f, "out {} {}", at, value),
+            Record::Halt(at) => write!(f, "halt {}", at),
+            Record::InputEnd(at) => write!(f, "eof {}", at),
+            Record::OutputClosed(at) => write!(f, "closed {}", at),
+        }
+    }
+}
+
+fn invalid(line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid session record '{}'", line),
+    )
+}
+
+// a trace that writes each record as soon as it happens, so an interrupted session
+// is still on file
+pub struct Recorder<W: Write> {
+    writer: W,
+    executed: u64,
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            executed: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write(&mut self, record: Record) {
+        writeln!(self.writer, "{}", record).ok();
+        self.writer.flush().ok();
+    }
+}
+
+impl Recorder<File> {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        File::create(path).map(Recorder::new)
+    }
+}
+
+impl<W: Write> Trace for Recorder<W> {
+    fn trace(&mut self, event: &TraceEvent) {
+        let at = self.executed;
+        self.executed += 1;
+        let record = match event.effect {
+            Effect::Input(_, value) => Record::Input(at, value),
+            Effect::Output(value) => Record::Output(at, value),
+            Effect::Halt => Record::Halt(at),
+            _ => return,
+        };
+        self.write(record);
+    }
+}
+
+// like run_traced with a Recorder, adding how the run ended when the IO ended it
+pub fn run_recorded<I: IO, W: Write>(
+    memory: Memory,
+    mut io: I,
+    mut recorder: Recorder<W>,
+) -> Result<(Memory, I::Value), VmError> {
+    let mut machine = Machine::new(memory);
+    loop {
+        match machine.resume_traced(&mut recorder)? {
+            Event::NeedsInput => match io.read() {
+                Some(value) => machine.input(value),
+                None => {
+                    recorder.write(Record::InputEnd(machine.executed()));
+                    break;
+                }
+            },
+            Event::Output(value) => {
+                if io.write(value).is_none() {
+                    recorder.write(Record::OutputClosed(machine.executed() - 1));
+                    break;
+                }
+            }
+            Event::Halted => break,
+        }
+    }
+    Ok((machine.into_memory(), io.output()))
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Session {
+    pub records: Vec<Record>,
+}
+
+impl Session {
+    pub fn read<R: BufRead>(reader: R) -> io::Result<Session> {
+        let mut records = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                records.push(Record::parse(&line)?);
+            }
+        }
+        Ok(Session { records })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Session> {
+        Session::read(BufReader::new(File::open(path)?))
+    }
+
+    pub fn inputs(&self) -> Vec<i64> {
+        self.records
+            .iter()
+            .filter_map(|record| match record {
+                Record::Input(_, value) => Some(*value),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn outputs(&self) -> Vec<i64> {
+        self.records
+            .iter()
+            .filter_map(|record| match record {
+                Record::Output(_, value) => Some(*value),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for record in &self.records {
+            writeln!(f, "{}", record)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReplayError {
+    Vm(VmError),
+    // the program did something else at instruction `at` than the session recorded
+    Diverged {
+        at: u64,
+        expected: Option<Record>,
+        found: Event,
+    },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayError::Vm(error) => write!(f, "{}", error),
+            ReplayError::Diverged {
+                at,
+                expected: Some(expected),
+                found,
+            } => write!(
+                f,
+                "diverged at instruction {}: expected '{}', found {:?}",
+                at, expected, found
+            ),
+            ReplayError::Diverged {
+                at,
+                expected: None,
+                found,
+            } => write!(
+                f,
+                "diverged at instruction {}: session ended, found {:?}",
+                at, found
+            ),
+        }
+    }
+}
+
+impl Error for ReplayError {}
+
+impl From<VmError> for ReplayError {
+    fn from(error: VmError) -> Self {
+        ReplayError::Vm(error)
+    }
+}
+
+// runs the program against the recorded inputs, checking every read, write and how
+// the run ended against the session; a session cut short, without an ending, may
+// still end on a read
+pub fn replay(memory: Memory, session: &Session) -> Result<Vec<i64>, ReplayError> {
+    let mut machine = Machine::new(memory);
+    let mut records = session.records.iter().copied().peekable();
+    let mut outputs = Vec::new();
+    loop {
+        let event = machine.resume()?;
+        let at = match event {
+            Event::NeedsInput => machine.executed(),
+            _ => machine.executed() - 1,
+        };
+        let expected = records.next();
+        match (event, expected) {
+            (Event::NeedsInput, Some(Record::Input(when, value))) if when == at => {
+                machine.input(value)
+            }
+            (Event::NeedsInput, Some(Record::InputEnd(when))) if when == at => break,
+            (Event::NeedsInput, None) => break,
+            (Event::Output(value), Some(Record::Output(when, recorded)))
+                if when == at && value == recorded =>
+            {
+                outputs.push(value);
+                if records.peek() == Some(&Record::OutputClosed(at)) {
+                    break;
+                }
+            }
+            (Event::Halted, Some(Record::Halt(when))) if when == at => break,
+            (found, expected) => {
+                return Err(ReplayError::Diverged {
+                    at,
+                    expected,
+                    found,
+                })
+            }
+        }
+    }
+    Ok(outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day9;
+    use crate::vm::{InputOutput, OutputExt};
+    use std::collections::VecDeque;
+    use std::env;
+    use std::fs;
+
+    const QUINE: &str = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    // reads values and writes each one doubled until its input ends
+    const DOUBLE: &str = "3,11,1002,11,2,11,4,11,1105,1,0,0";
+
+    // records a run to a file and loads it back
+    fn record<I: IO>(name: &str, program: &str, io: I) -> (I::Value, Session) {
+        let path = env::temp_dir().join(format!("{}-{}.session", name, std::process::id()));
+        let recorder = Recorder::create(&path).unwrap();
+        let (_, output) = run_recorded(Memory::from(program), io, recorder).unwrap();
+        let session = Session::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        (output, session)
+    }
+
+    #[test]
+    fn replays_each_way_a_run_ends() {
+        let (output, session) = record("halt", day9::INPUT, InputOutput::new(1, Vec::new()));
+        assert!(matches!(session.records.last(), Some(Record::Halt(_))));
+        assert_eq!(replay(Memory::from(day9::INPUT), &session), Ok(output));
+
+        let input = VecDeque::from(vec![3, 4]);
+        let (output, session) = record("eof", DOUBLE, InputOutput::new(input, Vec::new()));
+        assert_eq!(output, vec![6, 8]);
+        assert_eq!(session.records.last(), Some(&Record::InputEnd(8)));
+        assert_eq!(replay(Memory::from(DOUBLE), &session), Ok(output));
+
+        let io = InputOutput::new(VecDeque::new(), Vec::new().take_output(1));
+        let (output, session) = record("closed", QUINE, io);
+        assert_eq!(output, vec![109]);
+        assert_eq!(
+            session.records,
+            vec![
+                Record::Output(1, 109),
+                Record::Output(6, 1),
+                Record::OutputClosed(6)
+            ]
+        );
+        assert_eq!(replay(Memory::from(QUINE), &session), Ok(vec![109, 1]));
+    }
+
+    #[test]
+    fn reports_where_a_replay_diverges() {
+        let input = VecDeque::from(vec![3, 4]);
+        let (_, session) = record("diverged", DOUBLE, InputOutput::new(input, Vec::new()));
+        let triple = "3,11,1002,11,3,11,4,11,1105,1,0,0";
+        assert_eq!(
+            replay(Memory::from(triple), &session),
+            Err(ReplayError::Diverged {
+                at: 2,
+                expected: Some(Record::Output(2, 6)),
+                found: Event::Output(9),
+            })
+        );
+    }
+}
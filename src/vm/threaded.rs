@@ -0,0 +1,211 @@
+use crate::vm::cells::Cell;
+use crate::vm::error::VmError;
+use crate::vm::instructions::{InstructionSet, Op, MAX_PARAMS};
+use crate::vm::machine::Memory;
+use std::str::FromStr;
+
+// the threaded engine decodes the whole program when the machine is created and then
+// only looks instructions up, which pays off for programs that run long loops; a run
+// of a few hundred instructions spends more on decoding than it saves
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Engine {
+    Interpreter,
+    Threaded,
+}
+
+impl FromStr for Engine {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "interpreter" => Ok(Engine::Interpreter),
+            "threaded" => Ok(Engine::Threaded),
+            _ => Err(format!("unknown engine '{}'", name)),
+        }
+    }
+}
+
+// instructions this far past the end of the cache still grow it; ones further out, like
+// code in a far sparse page of memory, are decoded every time they run instead
+const CACHE_SLACK: usize = 64 * 1024;
+
+// the program decoded once per address; a write into any cell of a decoded
+// instruction drops it so that self-modifying code gets decoded again
+#[derive(Clone, Debug)]
+pub struct Code<C = i64> {
+    ops: Vec<Option<Op<C>>>,
+    // cells that belong to a decoded instruction, so that stores to data cells skip
+    // looking for instructions to drop
+    covered: Vec<bool>,
+}
+
+impl<C: Cell> Code<C> {
+    // decodes one instruction after the other from address 0, stepping over cells that
+    // do not decode; addresses reached some other way are decoded when first run
+    pub fn new(isa: &InstructionSet<C>, memory: &Memory<C>) -> Self {
+        let size = memory.len();
+        let mut code = Self {
+            ops: vec![None; size],
+            covered: vec![false; size],
+        };
+        let mut pc = 0;
+        while pc < size {
+            pc = match Op::decode(isa, memory, pc) {
+                Ok(op) => code.insert(pc, op),
+                Err(_) => pc + 1,
+            };
+        }
+        code
+    }
+
+    // caches op at pc unless it lies too far out, returning the address behind it
+    fn insert(&mut self, pc: usize, op: Op<C>) -> usize {
+        let next = pc + 1 + op.instruction.num_params;
+        if next > self.ops.len() + CACHE_SLACK {
+            return next;
+        }
+        if next > self.ops.len() {
+            self.ops.resize(next, None);
+            self.covered.resize(next, false);
+        }
+        self.ops[pc] = Some(op);
+        self.covered[pc..next].fill(true);
+        next
+    }
+
+    #[inline]
+    pub fn fetch(
+        &mut self,
+        isa: &InstructionSet<C>,
+        memory: &Memory<C>,
+        pc: usize,
+    ) -> Result<Op<C>, VmError> {
+        match self.ops.get(pc) {
+            Some(Some(op)) => Ok(op.clone()),
+            _ => self.decode(isa, memory, pc),
+        }
+    }
+
+    #[cold]
+    fn decode(
+        &mut self,
+        isa: &InstructionSet<C>,
+        memory: &Memory<C>,
+        pc: usize,
+    ) -> Result<Op<C>, VmError> {
+        let op = Op::decode(isa, memory, pc)?;
+        self.insert(pc, op.clone());
+        Ok(op)
+    }
+
+    #[inline]
+    pub fn invalidate(&mut self, position: usize) {
+        if self.covered.get(position) == Some(&true) {
+            self.drop_covering(position);
+        }
+    }
+
+    #[cold]
+    fn drop_covering(&mut self, position: usize) {
+        for pc in position.saturating_sub(MAX_PARAMS)..=position {
+            if let Some(op) = &self.ops[pc] {
+                if pc + op.instruction.num_params >= position {
+                    self.ops[pc] = None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{Budget, Event, Machine};
+    use crate::{day11, day13, day15, day17, day19, day2, day21, day23, day25, day5, day7, day9};
+
+    // runs both engines side by side, feeding both the same input whenever they ask,
+    // and checks that they agree on every event and on the state they end in
+    fn lockstep(program: &str, patches: &[(usize, i64)], input: &[i64]) {
+        let mut memory = Memory::from(program);
+        for &(address, value) in patches {
+            memory[address] = value;
+        }
+        let mut interpreter = Machine::new(memory.clone());
+        let mut threaded = Machine::with_engine(memory, Engine::Threaded);
+        let mut budgets = [Budget::instructions(1_000_000); 2];
+        let mut input = input.iter().cycle();
+        loop {
+            let expected = interpreter.resume_within(&mut budgets[0]);
+            let found = threaded.resume_within(&mut budgets[1]);
+            assert_eq!(
+                expected,
+                found,
+                "after {} instructions",
+                interpreter.executed()
+            );
+            match expected {
+                Ok(Some(Event::NeedsInput)) => {
+                    let value = *input.next().expect("the program wants input");
+                    interpreter.input(value);
+                    threaded.input(value);
+                }
+                Ok(Some(Event::Output(_))) => {}
+                _ => break,
+            }
+        }
+        assert_eq!(interpreter.executed(), threaded.executed());
+        assert_eq!(interpreter.pc(), threaded.pc());
+        assert_eq!(interpreter.rb(), threaded.rb());
+        assert_eq!(
+            interpreter.memory().to_string(),
+            threaded.memory().to_string()
+        );
+    }
+
+    fn ascii(text: &str) -> Vec<i64> {
+        text.bytes().map(i64::from).collect()
+    }
+
+    #[test]
+    fn engines_agree_on_the_day_programs() {
+        lockstep(day2::INPUT, &[(1, 12), (2, 2)], &[]);
+        lockstep(day5::INPUT, &[], &[1]);
+        lockstep(day5::INPUT, &[], &[5]);
+        lockstep(day7::INPUT, &[], &[4, 0]);
+        lockstep(day9::INPUT, &[], &[1]);
+        lockstep(day9::INPUT, &[], &[2]);
+        lockstep(day11::INPUT, &[], &[0, 1, 1, 0, 1]);
+        lockstep(day13::INPUT, &[(0, 2)], &[0, -1, 1, 1, 0]);
+        lockstep(day15::INPUT, &[], &[1, 4, 2, 3, 3]);
+        lockstep(day17::INPUT, &[], &[]);
+        lockstep(day19::INPUT, &[], &[10, 20, 33]);
+        lockstep(
+            day21::INPUT,
+            &[],
+            &ascii("NOT A J\nNOT B T\nOR T J\nWALK\n"),
+        );
+        lockstep(day23::INPUT, &[], &[3, -1]);
+        lockstep(day25::INPUT, &[], &ascii("north\ntake mug\ninv\n"));
+    }
+
+    // stores an OUT and a HLT a trillion cells out and jumps there; the cache must not
+    // grow to cover them
+    #[test]
+    fn far_code_is_run_uncached() {
+        let program = "1101,104,0,1000000000000,1101,7,0,1000000000001,\
+                       1101,99,0,1000000000002,1105,1,1000000000000";
+        lockstep(program, &[], &[]);
+        let mut machine = Machine::with_engine(Memory::from(program), Engine::Threaded);
+        assert_eq!(machine.resume(), Ok(Event::Output(7)));
+        assert_eq!(machine.resume(), Ok(Event::Halted));
+    }
+
+    // a store into the next instruction turns an ADD into a MUL after it was decoded
+    #[test]
+    fn self_modifying_code_is_decoded_again() {
+        let program = "1101,551,551,4,1101,6,7,13,4,13,99,0,0,0";
+        lockstep(program, &[], &[]);
+        let mut machine = Machine::with_engine(Memory::from(program), Engine::Threaded);
+        assert_eq!(machine.resume(), Ok(Event::Output(42)));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::vm::instructions::{Instruction, Param};
+use std::fmt::Display;
+use std::io::Write;
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Effect<C = i64> {
+    Store(usize, C),
+    Input(usize, C),
+    Output(C),
+    Jump(usize),
+    RelativeBase(i64),
+    None,
+    Halt,
+}
+
+pub struct TraceEvent<'a, C = i64> {
+    pub pc: usize,
+    pub opcode: i64,
+    pub instruction: &'a Instruction<C>,
+    pub params: &'a [Param<C>],
+    pub effect: Effect<C>,
+}
+
+pub trait Trace<C = i64> {
+    fn trace(&mut self, event: &TraceEvent<C>);
+}
+
+pub struct NullTrace;
+
+impl<C> Trace<C> for NullTrace {
+    fn trace(&mut self, _event: &TraceEvent<C>) {}
+}
+
+impl<C, T: Trace<C>> Trace<C> for &mut T {
+    fn trace(&mut self, event: &TraceEvent<C>) {
+        (**self).trace(event)
+    }
+}
+
+pub struct Filter<T> {
+    inner: T,
+    ranges: Vec<Range<usize>>,
+    mnemonics: Vec<String>,
+}
+
+impl<T> Filter<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            ranges: Vec::new(),
+            mnemonics: Vec::new(),
+        }
+    }
+
+    pub fn pcs(mut self, range: Range<usize>) -> Self {
+        self.ranges.push(range);
+        self
+    }
+
+    pub fn mnemonic(mut self, mnemonic: &str) -> Self {
+        self.mnemonics.push(mnemonic.to_string());
+        self
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<C, T: Trace<C>> Trace<C> for Filter<T> {
+    fn trace(&mut self, event: &TraceEvent<C>) {
+        if (self.ranges.is_empty() || self.ranges.iter().any(|range| range.contains(&event.pc)))
+            && (self.mnemonics.is_empty()
+                || self
+                    .mnemonics
+                    .iter()
+                    .any(|mnemonic| mnemonic.eq_ignore_ascii_case(event.instruction.mnemonic)))
+        {
+            self.inner.trace(event);
+        }
+    }
+}
+
+pub struct TextTrace<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> TextTrace<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<C: Display, W: Write> Trace<C> for TextTrace<W> {
+    fn trace(&mut self, event: &TraceEvent<C>) {
+        let params: Vec<String> = event
+            .params
+            .iter()
+            .map(|param| match param {
+                Param::Positional(position, value) => format!("[{}]={}", position, value),
+                Param::Immediate(value) => format!("#{}", value),
+                Param::Relative(position, value) => format!("[{}]={}", position, value),
+            })
+            .collect();
+        let effect = match &event.effect {
+            Effect::Store(position, value) => format!("[{}] <- {}", position, value),
+            Effect::Input(position, value) => format!("[{}] <- in {}", position, value),
+            Effect::Output(value) => format!("out {}", value),
+            Effect::Jump(to) => format!("jump {}", to),
+            Effect::RelativeBase(rb) => format!("rb {}", rb),
+            Effect::None => String::new(),
+            Effect::Halt => "halt".to_string(),
+        };
+        let line = format!(
+            "{:>5}  {:<4} {:<40} {}",
+            event.pc,
+            event.instruction.mnemonic,
+            params.join(" "),
+            effect
+        );
+        writeln!(self.writer, "{}", line.trim_end()).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{run_traced, InputOutput, Memory, NullIO};
+
+    // sets a counter to 3, then decrements it until it is 0
+    const COUNTDOWN: &str = "1101,3,0,20,1001,20,-1,20,1005,20,4,99";
+
+    #[derive(Default)]
+    struct Collect(Vec<(usize, &'static str)>);
+
+    impl<C> Trace<C> for Collect {
+        fn trace(&mut self, event: &TraceEvent<C>) {
+            self.0.push((event.pc, event.instruction.mnemonic));
+        }
+    }
+
+    fn traced(mut filter: Filter<Collect>) -> Vec<(usize, &'static str)> {
+        let io = InputOutput::new(NullIO::new(), NullIO::new());
+        run_traced(Memory::from(COUNTDOWN), io, &mut filter).unwrap();
+        filter.into_inner().0
+    }
+
+    #[test]
+    fn unfiltered_sees_every_instruction() {
+        let mut expected = vec![(0, "ADD")];
+        for _ in 0..3 {
+            expected.extend([(4, "ADD"), (8, "JNZ")]);
+        }
+        expected.push((11, "HALT"));
+        assert_eq!(traced(Filter::new(Collect::default())), expected);
+    }
+
+    #[test]
+    fn filters_by_pc_range() {
+        let filter = Filter::new(Collect::default()).pcs(4..8);
+        assert_eq!(traced(filter), vec![(4, "ADD"); 3]);
+        let filter = Filter::new(Collect::default()).pcs(0..1).pcs(11..12);
+        assert_eq!(traced(filter), vec![(0, "ADD"), (11, "HALT")]);
+        let filter = Filter::new(Collect::default()).pcs(12..100);
+        assert_eq!(traced(filter), vec![]);
+    }
+
+    #[test]
+    fn filters_by_mnemonic() {
+        let filter = Filter::new(Collect::default()).mnemonic("jnz");
+        assert_eq!(traced(filter), vec![(8, "JNZ"); 3]);
+        let filter = Filter::new(Collect::default())
+            .mnemonic("HALT")
+            .mnemonic("Add")
+            .pcs(0..4);
+        assert_eq!(traced(filter), vec![(0, "ADD")]);
+    }
+}
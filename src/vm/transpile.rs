@@ -0,0 +1,201 @@
+use crate::vm::disasm::{disassemble, Decoded, Item, Operand};
+use crate::vm::error::VmError;
+use crate::vm::io::IO;
+use crate::vm::machine::{Event, Machine, Memory};
+use std::fmt::Write;
+
+// the runtime of transpiled programs: a position that may be read or written
+pub fn checked_address(memory: &Memory, value: i64) -> Option<usize> {
+    Some(value)
+        .filter(|value| *value >= 0)
+        .map(|value| value as usize)
+        .filter(|address| memory.in_range(*address))
+}
+
+pub fn checked_relative(memory: &Memory, rb: i64, offset: i64) -> Option<usize> {
+    checked_address(memory, rb.checked_add(offset)?)
+}
+
+// carries on with the interpreter wherever the transpiled code gives up: code it has
+// not seen, an instruction that was overwritten or anything that would fault, which
+// the interpreter then reports
+pub fn interpret_from<I: IO>(
+    memory: Memory,
+    pc: usize,
+    rb: i64,
+    mut io: I,
+) -> Result<(Memory, I::Value), VmError> {
+    let mut machine = Machine::new(memory).with_registers(pc, rb);
+    loop {
+        let running = match machine.resume()? {
+            Event::NeedsInput => io.read().map(|value| machine.input(value)).is_some(),
+            Event::Output(value) => io.write(value).is_some(),
+            Event::Halted => false,
+        };
+        if !running {
+            break;
+        }
+    }
+    Ok((machine.into_memory(), io.output()))
+}
+
+// the statement loading operand `index` into `name`; operands are read from memory on
+// every run, since programs patch their own addresses
+fn load(code: &mut String, decoded: &Decoded, index: usize, name: &str) {
+    let at = decoded.address + 1 + index;
+    let expression = match decoded.operands[index] {
+        Operand::Immediate(_) => {
+            writeln!(code, "                    let {} = memory[{}];", name, at).unwrap();
+            return;
+        }
+        Operand::Position(_) => format!("checked_address(&memory, memory[{}])", at),
+        Operand::Relative(_) => format!("checked_relative(&memory, rb, memory[{}])", at),
+    };
+    writeln!(
+        code,
+        "                    let Some({}) = {}.map(|at| memory[at]) else {{ break }};",
+        name, expression
+    )
+    .unwrap();
+}
+
+fn target(code: &mut String, decoded: &Decoded, index: usize) {
+    let at = decoded.address + 1 + index;
+    let expression = match decoded.operands[index] {
+        Operand::Relative(_) => format!("checked_relative(&memory, rb, memory[{}])", at),
+        _ => format!("checked_address(&memory, memory[{}])", at),
+    };
+    writeln!(
+        code,
+        "                    let Some(to) = {} else {{ break }};",
+        expression
+    )
+    .unwrap();
+}
+
+// the match arm of one instruction; everything that can fail comes before the first
+// effect, so breaking out leaves the instruction for the interpreter to run again
+fn arm(code: &mut String, decoded: &Decoded) {
+    let next = decoded.next();
+    writeln!(
+        code,
+        "                {} if memory[{}] == {} => {{",
+        decoded.address, decoded.address, decoded.opcode
+    )
+    .unwrap();
+    match decoded.opcode % 100 {
+        op @ (1 | 2) => {
+            load(code, decoded, 0, "a");
+            load(code, decoded, 1, "b");
+            target(code, decoded, 2);
+            let method = if op == 1 {
+                "checked_add"
+            } else {
+                "checked_mul"
+            };
+            writeln!(
+                code,
+                "                    let Some(value) = a.{}(b) else {{ break }};",
+                method
+            )
+            .unwrap();
+            writeln!(code, "                    memory[to] = value;").unwrap();
+        }
+        3 => {
+            target(code, decoded, 0);
+            writeln!(
+                code,
+                "                    let Some(value) = io.read() else {{ break 'run }};"
+            )
+            .unwrap();
+            writeln!(code, "                    memory[to] = value;").unwrap();
+        }
+        4 => {
+            load(code, decoded, 0, "a");
+            writeln!(
+                code,
+                "                    if io.write(a).is_none() {{ break 'run }}"
+            )
+            .unwrap();
+        }
+        op @ (5 | 6) => {
+            load(code, decoded, 0, "a");
+            load(code, decoded, 1, "b");
+            let condition = if op == 5 { "a != 0" } else { "a == 0" };
+            writeln!(code, "                    if {} {{", condition).unwrap();
+            writeln!(code, "                        if b < 0 {{ break }}").unwrap();
+            writeln!(code, "                        pc = b as usize;").unwrap();
+            writeln!(code, "                        continue;").unwrap();
+            writeln!(code, "                    }}").unwrap();
+        }
+        op @ (7 | 8) => {
+            load(code, decoded, 0, "a");
+            load(code, decoded, 1, "b");
+            target(code, decoded, 2);
+            let comparison = if op == 7 { "<" } else { "==" };
+            writeln!(
+                code,
+                "                    memory[to] = (a {} b) as i64;",
+                comparison
+            )
+            .unwrap();
+        }
+        9 => {
+            load(code, decoded, 0, "a");
+            writeln!(
+                code,
+                "                    let Some(value) = rb.checked_add(a) else {{ break }};"
+            )
+            .unwrap();
+            writeln!(code, "                    rb = value;").unwrap();
+        }
+        _ => {
+            writeln!(code, "                    break 'run;").unwrap();
+            writeln!(code, "                }}").unwrap();
+            return;
+        }
+    }
+    writeln!(code, "                    pc = {};", next).unwrap();
+    writeln!(code, "                }}").unwrap();
+}
+
+// Rust source for a module with a `run` that behaves like vm::run on this program,
+// for checked arithmetic and the standard instruction set
+pub fn transpile(memory: &Memory) -> String {
+    let mut code = String::new();
+    writeln!(code, "// generated by the transpile tool, do not edit").unwrap();
+    writeln!(
+        code,
+        "use crate::vm::{{checked_address, checked_relative, interpret_from, Memory, VmError, IO}};"
+    )
+    .unwrap();
+    writeln!(code).unwrap();
+    writeln!(
+        code,
+        "#[allow(unused_mut, unused_assignments, clippy::all)]"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "pub fn run<I: IO>(mut memory: Memory, mut io: I) -> Result<(Memory, I::Value), VmError> {{"
+    )
+    .unwrap();
+    writeln!(code, "    let mut pc: usize = 0;").unwrap();
+    writeln!(code, "    let mut rb: i64 = 0;").unwrap();
+    writeln!(code, "    'run: {{").unwrap();
+    writeln!(code, "        loop {{").unwrap();
+    writeln!(code, "            match pc {{").unwrap();
+    for item in &disassemble(memory).items {
+        if let Item::Code(decoded) = item {
+            arm(&mut code, decoded);
+        }
+    }
+    writeln!(code, "                _ => break,").unwrap();
+    writeln!(code, "            }}").unwrap();
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "        return interpret_from(memory, pc, rb, io);").unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "    Ok((memory, io.output()))").unwrap();
+    writeln!(code, "}}").unwrap();
+    code
+}